@@ -0,0 +1,394 @@
+-- Brings a database created from an older `db/scheme.sql` up to date:
+-- adds the new columns to existing tables, creates the new tables
+-- and the `newer_source_revision_counts` view.
+-- Run the backfills in 002 and 003 right after it
+
+USE `kotoba`;
+
+-- Languages get a BCP-47 code, a native name, a script, a direction and a parent.
+-- Existing languages are matched by their English name,
+-- the ones that aren't recognized get a `und-x-<id>` placeholder code to be fixed by hand
+
+ALTER TABLE `languages`
+  ADD COLUMN `code` varchar(16) DEFAULT NULL AFTER `id`,
+  ADD COLUMN `native_name` varchar(128) DEFAULT NULL AFTER `name`,
+  ADD COLUMN `script` char(4) DEFAULT NULL AFTER `native_name`,
+  ADD COLUMN `direction` enum('Ltr','Rtl') NOT NULL DEFAULT 'Ltr' AFTER `script`,
+  ADD COLUMN `parent_id` int DEFAULT NULL AFTER `direction`;
+
+UPDATE `languages`
+SET
+  `code` = CASE LOWER(`name`)
+    WHEN 'arabic' THEN 'ar'
+    WHEN 'chinese' THEN 'zh'
+    WHEN 'czech' THEN 'cs'
+    WHEN 'dutch' THEN 'nl'
+    WHEN 'english' THEN 'en'
+    WHEN 'french' THEN 'fr'
+    WHEN 'german' THEN 'de'
+    WHEN 'greek' THEN 'el'
+    WHEN 'hebrew' THEN 'he'
+    WHEN 'hindi' THEN 'hi'
+    WHEN 'italian' THEN 'it'
+    WHEN 'japanese' THEN 'ja'
+    WHEN 'korean' THEN 'ko'
+    WHEN 'persian' THEN 'fa'
+    WHEN 'polish' THEN 'pl'
+    WHEN 'portuguese' THEN 'pt'
+    WHEN 'russian' THEN 'ru'
+    WHEN 'spanish' THEN 'es'
+    WHEN 'swedish' THEN 'sv'
+    WHEN 'turkish' THEN 'tr'
+    WHEN 'ukrainian' THEN 'uk'
+    ELSE CONCAT('und-x-', `id`)
+  END,
+  `native_name` = `name`,
+  `direction` = IF(LOWER(`name`) IN ('arabic', 'hebrew', 'persian'), 'Rtl', 'Ltr');
+
+ALTER TABLE `languages`
+  MODIFY COLUMN `code` varchar(16) NOT NULL,
+  MODIFY COLUMN `native_name` varchar(128) NOT NULL,
+  ADD UNIQUE KEY `code` (`code`),
+  ADD KEY `parent_id` (`parent_id`),
+  ADD CONSTRAINT `languages_ibfk_1` FOREIGN KEY (`parent_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE;
+
+ALTER TABLE `users`
+  ADD COLUMN `prefers_source_language` tinyint(1) NOT NULL DEFAULT '0' AFTER `is_following_private`,
+  ADD COLUMN `is_digest_enabled` tinyint(1) NOT NULL DEFAULT '0' AFTER `prefers_source_language`,
+  ADD COLUMN `digest_sent_at` datetime DEFAULT NULL AFTER `is_digest_enabled`,
+  ADD COLUMN `unsubscribe_token` varchar(36) DEFAULT NULL AFTER `retoken`,
+  ADD UNIQUE KEY `unsubscribe_token` (`unsubscribe_token`);
+
+ALTER TABLE `posts`
+  ADD COLUMN `source_post_content_id` int DEFAULT NULL AFTER `posted_at`,
+  ADD COLUMN `deleted_by` int DEFAULT NULL AFTER `source_post_content_id`,
+  ADD COLUMN `deleted_at` datetime DEFAULT NULL AFTER `deleted_by`,
+  ADD KEY `source_post_content_id` (`source_post_content_id`),
+  ADD KEY `deleted_by` (`deleted_by`),
+  ADD KEY `deleted_at` (`deleted_at`),
+  ADD CONSTRAINT `posts_ibfk_2` FOREIGN KEY (`deleted_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  ADD CONSTRAINT `posts_ibfk_3` FOREIGN KEY (`source_post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE;
+
+ALTER TABLE `comments`
+  ADD COLUMN `edited_at` datetime DEFAULT NULL AFTER `posted_at`,
+  ADD COLUMN `deleted_by` int DEFAULT NULL AFTER `edited_at`,
+  ADD COLUMN `deleted_at` datetime DEFAULT NULL AFTER `deleted_by`,
+  ADD KEY `deleted_by` (`deleted_by`),
+  ADD CONSTRAINT `comments_ibfk_4` FOREIGN KEY (`deleted_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE;
+
+CREATE TABLE `post_content_revisions` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_content_id` int NOT NULL,
+  `language_id` int NOT NULL,
+  `title` varchar(128) NOT NULL,
+  `description` varchar(256) NOT NULL,
+  `content` varchar(8192) NOT NULL,
+  `revised_by` int NOT NULL,
+  `revised_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `post_content_id` (`post_content_id`),
+  KEY `language_id` (`language_id`),
+  KEY `revised_by` (`revised_by`),
+  CONSTRAINT `post_content_revisions_ibfk_1` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `post_content_revisions_ibfk_2` FOREIGN KEY (`language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `post_content_revisions_ibfk_3` FOREIGN KEY (`revised_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+ALTER TABLE `post_contents`
+  MODIFY COLUMN `status` enum('Pending','Approved','Denied','ChangesRequested','') NOT NULL,
+  ADD COLUMN `is_machine_translated` tinyint(1) NOT NULL DEFAULT '0' AFTER `translated_at`,
+  ADD COLUMN `variant` varchar(32) DEFAULT NULL AFTER `is_machine_translated`,
+  ADD COLUMN `source_revision_id` int DEFAULT NULL AFTER `variant`,
+  ADD COLUMN `deleted_by` int DEFAULT NULL AFTER `source_revision_id`,
+  ADD COLUMN `deleted_at` datetime DEFAULT NULL AFTER `deleted_by`,
+  ADD KEY `source_revision_id` (`source_revision_id`),
+  ADD KEY `deleted_by` (`deleted_by`),
+  ADD KEY `deleted_at` (`deleted_at`),
+  ADD CONSTRAINT `post_contents_ibfk_4` FOREIGN KEY (`deleted_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  ADD CONSTRAINT `post_contents_ibfk_5` FOREIGN KEY (`source_revision_id`) REFERENCES `post_content_revisions` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE;
+
+CREATE TABLE `post_content_revision_tags` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_content_revision_id` int NOT NULL,
+  `tag_id` int NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `post_content_revision_id` (`post_content_revision_id`),
+  KEY `tag_id` (`tag_id`),
+  CONSTRAINT `post_content_revision_tags_ibfk_1` FOREIGN KEY (`post_content_revision_id`) REFERENCES `post_content_revisions` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `post_content_revision_tags_ibfk_2` FOREIGN KEY (`tag_id`) REFERENCES `tags` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `comment_revisions` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `comment_id` int NOT NULL,
+  `content` varchar(4096) NOT NULL,
+  `written_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `comment_id` (`comment_id`),
+  CONSTRAINT `comment_revisions_ibfk_1` FOREIGN KEY (`comment_id`) REFERENCES `comments` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `translation_reviews` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_content_id` int NOT NULL,
+  `paragraph` int DEFAULT NULL,
+  `reply_to` int DEFAULT NULL,
+  `content` varchar(4096) NOT NULL,
+  `posted_by` int NOT NULL,
+  `posted_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `post_content_id` (`post_content_id`),
+  KEY `reply_to` (`reply_to`),
+  KEY `posted_by` (`posted_by`),
+  CONSTRAINT `translation_reviews_ibfk_1` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_reviews_ibfk_2` FOREIGN KEY (`reply_to`) REFERENCES `translation_reviews` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_reviews_ibfk_3` FOREIGN KEY (`posted_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `translation_suggestions` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_content_id` int NOT NULL,
+  `paragraph` int NOT NULL,
+  `original` varchar(8192) NOT NULL,
+  `content` varchar(8192) NOT NULL,
+  `status` enum('Pending','Accepted','Rejected') NOT NULL,
+  `suggested_by` int NOT NULL,
+  `suggested_at` datetime NOT NULL,
+  `resolved_by` int DEFAULT NULL,
+  `resolved_at` datetime DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  KEY `post_content_id` (`post_content_id`),
+  KEY `suggested_by` (`suggested_by`),
+  KEY `resolved_by` (`resolved_by`),
+  CONSTRAINT `translation_suggestions_ibfk_1` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_suggestions_ibfk_2` FOREIGN KEY (`suggested_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_suggestions_ibfk_3` FOREIGN KEY (`resolved_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `translation_claims` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_id` int NOT NULL,
+  `language_id` int NOT NULL,
+  `claimed_by` int NOT NULL,
+  `claimed_at` datetime NOT NULL,
+  `expires_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `post_id_language_id` (`post_id`,`language_id`),
+  KEY `language_id` (`language_id`),
+  KEY `claimed_by` (`claimed_by`),
+  KEY `expires_at` (`expires_at`),
+  CONSTRAINT `translation_claims_ibfk_1` FOREIGN KEY (`post_id`) REFERENCES `posts` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_claims_ibfk_2` FOREIGN KEY (`language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_claims_ibfk_3` FOREIGN KEY (`claimed_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `translation_requests` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_id` int NOT NULL,
+  `language_id` int NOT NULL,
+  `requested_by` int NOT NULL,
+  `requested_at` datetime NOT NULL,
+  `post_content_id` int DEFAULT NULL,
+  `fulfilled_at` datetime DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  KEY `post_id_language_id` (`post_id`,`language_id`),
+  KEY `language_id` (`language_id`),
+  KEY `requested_by` (`requested_by`),
+  KEY `post_content_id` (`post_content_id`),
+  KEY `fulfilled_at` (`fulfilled_at`),
+  CONSTRAINT `translation_requests_ibfk_1` FOREIGN KEY (`post_id`) REFERENCES `posts` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_requests_ibfk_2` FOREIGN KEY (`language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_requests_ibfk_3` FOREIGN KEY (`requested_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_requests_ibfk_4` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `translation_drafts` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_id` int NOT NULL,
+  `language_id` int NOT NULL,
+  `drafted_by` int NOT NULL,
+  `drafted_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `post_id_language_id_drafted_by` (`post_id`,`language_id`,`drafted_by`),
+  KEY `language_id` (`language_id`),
+  KEY `drafted_by` (`drafted_by`),
+  KEY `drafted_at` (`drafted_at`),
+  CONSTRAINT `translation_drafts_ibfk_1` FOREIGN KEY (`post_id`) REFERENCES `posts` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_drafts_ibfk_2` FOREIGN KEY (`language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_drafts_ibfk_3` FOREIGN KEY (`drafted_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `translation_memory` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_content_id` int NOT NULL,
+  `source_language_id` int NOT NULL,
+  `target_language_id` int NOT NULL,
+  `source_segment` varchar(2048) NOT NULL,
+  `target_segment` varchar(2048) NOT NULL,
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `post_content_id` (`post_content_id`),
+  KEY `language_pair` (`source_language_id`,`target_language_id`),
+  KEY `target_language_id` (`target_language_id`),
+  KEY `source_segment` (`source_segment`(255)),
+  CONSTRAINT `translation_memory_ibfk_1` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_memory_ibfk_2` FOREIGN KEY (`source_language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_memory_ibfk_3` FOREIGN KEY (`target_language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `glossary_terms` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `scope` enum('Post','Author','Site') NOT NULL,
+  `post_id` int DEFAULT NULL,
+  `author_id` int DEFAULT NULL,
+  `language_id` int NOT NULL,
+  `term` varchar(128) NOT NULL,
+  `created_by` int NOT NULL,
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `post_id` (`post_id`),
+  KEY `author_id` (`author_id`),
+  KEY `language_id` (`language_id`),
+  KEY `created_by` (`created_by`),
+  CONSTRAINT `glossary_terms_ibfk_1` FOREIGN KEY (`post_id`) REFERENCES `posts` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `glossary_terms_ibfk_2` FOREIGN KEY (`author_id`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `glossary_terms_ibfk_3` FOREIGN KEY (`language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `glossary_terms_ibfk_4` FOREIGN KEY (`created_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `glossary_term_translations` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `glossary_term_id` int NOT NULL,
+  `language_id` int NOT NULL,
+  `translation` varchar(128) NOT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `glossary_term_id_language_id` (`glossary_term_id`,`language_id`),
+  KEY `language_id` (`language_id`),
+  CONSTRAINT `glossary_term_translations_ibfk_1` FOREIGN KEY (`glossary_term_id`) REFERENCES `glossary_terms` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `glossary_term_translations_ibfk_2` FOREIGN KEY (`language_id`) REFERENCES `languages` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `translation_votes` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `post_content_id` int NOT NULL,
+  `voted_by` int NOT NULL,
+  `voted_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `post_content_id_voted_by` (`post_content_id`,`voted_by`),
+  KEY `voted_by` (`voted_by`),
+  CONSTRAINT `translation_votes_ibfk_1` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `translation_votes_ibfk_2` FOREIGN KEY (`voted_by`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `mentions` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `user_id` int NOT NULL,
+  `comment_id` int DEFAULT NULL,
+  `post_content_id` int DEFAULT NULL,
+  `start_offset` int NOT NULL,
+  `end_offset` int NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `user_id` (`user_id`),
+  KEY `comment_id` (`comment_id`),
+  KEY `post_content_id` (`post_content_id`),
+  CONSTRAINT `mentions_ibfk_1` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `mentions_ibfk_2` FOREIGN KEY (`comment_id`) REFERENCES `comments` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `mentions_ibfk_3` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `notifications` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `user_id` int NOT NULL,
+  `kind` enum('Mention','Reply','Follow','TranslationSubmitted','TranslationApproved','TranslationDenied','ChangesRequested','TranslationRequestFulfilled') NOT NULL,
+  `actor_id` int NOT NULL,
+  `post_content_id` int DEFAULT NULL,
+  `comment_id` int DEFAULT NULL,
+  `created_at` datetime NOT NULL,
+  `read_at` datetime DEFAULT NULL,
+  `emailed_at` datetime DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  KEY `user_id` (`user_id`),
+  KEY `actor_id` (`actor_id`),
+  KEY `post_content_id` (`post_content_id`),
+  KEY `comment_id` (`comment_id`),
+  CONSTRAINT `notifications_ibfk_1` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `notifications_ibfk_2` FOREIGN KEY (`actor_id`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `notifications_ibfk_3` FOREIGN KEY (`post_content_id`) REFERENCES `post_contents` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `notifications_ibfk_4` FOREIGN KEY (`comment_id`) REFERENCES `comments` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `notification_preferences` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `user_id` int NOT NULL,
+  `kind` enum('Mention','Reply','Follow','TranslationSubmitted','TranslationApproved','TranslationDenied','ChangesRequested','TranslationRequestFulfilled') NOT NULL,
+  `is_enabled` tinyint(1) NOT NULL,
+  `is_emailed` tinyint(1) NOT NULL DEFAULT '0',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `user_id_kind` (`user_id`,`kind`),
+  CONSTRAINT `notification_preferences_ibfk_1` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `realtime_events` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `payload` varchar(1024) NOT NULL,
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `created_at` (`created_at`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `webhooks` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `user_id` int NOT NULL,
+  `url` varchar(2048) NOT NULL,
+  `secret` varchar(64) NOT NULL,
+  `events` set('PostCreated','PostEdited','PostDeleted','TranslationSubmitted','TranslationApproved','TranslationDenied','CommentCreated') NOT NULL,
+  `is_global` tinyint(1) NOT NULL DEFAULT '0',
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `user_id` (`user_id`),
+  CONSTRAINT `webhooks_ibfk_1` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `webhook_deliveries` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `webhook_id` int NOT NULL,
+  `event` enum('PostCreated','PostEdited','PostDeleted','TranslationSubmitted','TranslationApproved','TranslationDenied','CommentCreated','Ping') NOT NULL,
+  `payload` text NOT NULL,
+  `attempt_count` int NOT NULL DEFAULT '0',
+  `next_attempt_at` datetime DEFAULT NULL,
+  `last_status_code` int DEFAULT NULL,
+  `last_error` varchar(1024) DEFAULT NULL,
+  `delivered_at` datetime DEFAULT NULL,
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `webhook_id` (`webhook_id`),
+  KEY `next_attempt_at` (`next_attempt_at`),
+  CONSTRAINT `webhook_deliveries_ibfk_1` FOREIGN KEY (`webhook_id`) REFERENCES `webhooks` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE TABLE `reactions` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `user_id` int NOT NULL,
+  `post_id` int DEFAULT NULL,
+  `comment_id` int DEFAULT NULL,
+  `emoji` varchar(32) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin NOT NULL,
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `post_id_user_id_emoji` (`post_id`,`user_id`,`emoji`),
+  UNIQUE KEY `comment_id_user_id_emoji` (`comment_id`,`user_id`,`emoji`),
+  KEY `user_id` (`user_id`),
+  CONSTRAINT `reactions_ibfk_1` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `reactions_ibfk_2` FOREIGN KEY (`post_id`) REFERENCES `posts` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE,
+  CONSTRAINT `reactions_ibfk_3` FOREIGN KEY (`comment_id`) REFERENCES `comments` (`id`) ON DELETE RESTRICT ON UPDATE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8 COLLATE=utf8_general_ci;
+
+CREATE OR REPLACE SQL SECURITY DEFINER VIEW `newer_source_revision_counts` AS
+SELECT
+  `post_contents`.`id` AS `post_content_id`,
+  COUNT(`newer_revisions`.`id`) AS `newer_source_revision_count`
+FROM `post_contents`
+JOIN `post_content_revisions` AS `source_revisions` ON `source_revisions`.`id` = `post_contents`.`source_revision_id`
+JOIN `post_content_revisions` AS `newer_revisions`
+  ON `newer_revisions`.`post_content_id` = `source_revisions`.`post_content_id`
+  AND `newer_revisions`.`id` > `source_revisions`.`id`
+GROUP BY `post_contents`.`id`;
@@ -0,0 +1,37 @@
+-- Records the source content of posts created before `source_post_content_id`
+-- existed: the earliest content written by the author, or the earliest content
+-- at all for posts whose author never wrote one
+
+USE `kotoba`;
+
+START TRANSACTION;
+
+CREATE TEMPORARY TABLE `source_post_contents` (
+  `post_id` int NOT NULL,
+  `post_content_id` int NOT NULL,
+  PRIMARY KEY (`post_id`)
+);
+
+INSERT INTO `source_post_contents`
+(
+  `post_id`,
+  `post_content_id`
+)
+SELECT
+  `posts`.`id`,
+  COALESCE(
+    MIN(CASE WHEN `post_contents`.`translated_by` = `posts`.`posted_by` THEN `post_contents`.`id` END),
+    MIN(`post_contents`.`id`)
+  )
+FROM `posts`
+JOIN `post_contents` ON `post_contents`.`post_id` = `posts`.`id`
+WHERE `posts`.`source_post_content_id` IS NULL
+GROUP BY `posts`.`id`;
+
+UPDATE `posts`
+JOIN `source_post_contents` ON `posts`.`id` = `source_post_contents`.`post_id`
+SET `posts`.`source_post_content_id` = `source_post_contents`.`post_content_id`;
+
+DROP TEMPORARY TABLE `source_post_contents`;
+
+COMMIT;
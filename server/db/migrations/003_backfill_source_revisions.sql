@@ -0,0 +1,91 @@
+-- Gives content written before revisions were recorded a baseline revision
+-- and points translations without a `source_revision_id` at the first
+-- revision of their source, so they can be flagged as outdated.
+-- Needs the source contents backfilled by 002
+
+USE `kotoba`;
+
+START TRANSACTION;
+
+CREATE TEMPORARY TABLE `unrevised_post_contents` (
+  `id` int NOT NULL,
+  PRIMARY KEY (`id`)
+);
+
+INSERT INTO `unrevised_post_contents` (`id`)
+SELECT `post_contents`.`id`
+FROM `post_contents`
+WHERE NOT EXISTS (
+  SELECT 1
+  FROM `post_content_revisions`
+  WHERE `post_content_revisions`.`post_content_id` = `post_contents`.`id`
+);
+
+INSERT INTO `post_content_revisions`
+(
+  `post_content_id`,
+  `language_id`,
+  `title`,
+  `description`,
+  `content`,
+  `revised_by`,
+  `revised_at`
+)
+SELECT
+  `post_contents`.`id`,
+  `post_contents`.`language_id`,
+  `post_contents`.`title`,
+  `post_contents`.`description`,
+  `post_contents`.`content`,
+  `post_contents`.`translated_by`,
+  `post_contents`.`translated_at`
+FROM `post_contents`
+JOIN `unrevised_post_contents` ON `post_contents`.`id` = `unrevised_post_contents`.`id`;
+
+INSERT INTO `post_content_revision_tags`
+(
+  `post_content_revision_id`,
+  `tag_id`
+)
+SELECT
+  `post_content_revisions`.`id`,
+  `post_content_tags`.`tag_id`
+FROM `post_content_revisions`
+JOIN `unrevised_post_contents` ON `post_content_revisions`.`post_content_id` = `unrevised_post_contents`.`id`
+JOIN `post_content_tags` ON `post_content_revisions`.`post_content_id` = `post_content_tags`.`post_content_id`;
+
+DROP TEMPORARY TABLE `unrevised_post_contents`;
+
+CREATE TEMPORARY TABLE `first_source_revisions` (
+  `post_id` int NOT NULL,
+  `post_content_id` int NOT NULL,
+  `revision_id` int NOT NULL,
+  PRIMARY KEY (`post_id`)
+);
+
+INSERT INTO `first_source_revisions`
+(
+  `post_id`,
+  `post_content_id`,
+  `revision_id`
+)
+SELECT
+  `posts`.`id`,
+  `post_content_revisions`.`post_content_id`,
+  MIN(`post_content_revisions`.`id`)
+FROM `posts`
+JOIN `post_content_revisions` ON `post_content_revisions`.`post_content_id` = `posts`.`source_post_content_id`
+GROUP BY
+  `posts`.`id`,
+  `post_content_revisions`.`post_content_id`;
+
+UPDATE `post_contents`
+JOIN `first_source_revisions` ON `post_contents`.`post_id` = `first_source_revisions`.`post_id`
+SET `post_contents`.`source_revision_id` = `first_source_revisions`.`revision_id`
+WHERE
+  `post_contents`.`source_revision_id` IS NULL
+  AND `post_contents`.`id` <> `first_source_revisions`.`post_content_id`;
+
+DROP TEMPORARY TABLE `first_source_revisions`;
+
+COMMIT;
@@ -0,0 +1,160 @@
+use crate::db::id::Id;
+use crate::db::language::fetch_language_codes;
+use actix_web::http::header::ACCEPT_LANGUAGE;
+use actix_web::HttpRequest;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+use std::collections::HashMap;
+
+// Browsers don't send that many, anything past this is noise
+const RANGES_MAX_AMOUNT: usize = 16;
+
+// Language ranges of an `Accept-Language` header, most preferred first.
+// `da, en-GB;q=0.8, en;q=0.7` -> `["da", "en-GB", "en"]`.
+// Ranges with `q=0` or a malformed weight are dropped, so is the `*` wildcard
+pub fn parse(header: &str) -> Vec<String> {
+    let mut ranges: Vec<(String, f32)> = header
+        .split(',')
+        .take(RANGES_MAX_AMOUNT)
+        .filter_map(|item| {
+            let mut parameters = item.split(';');
+
+            let range = parameters
+                .next()
+                .expect("`split` always yields at least one item")
+                .trim();
+            if range.is_empty() || range == "*" {
+                return None;
+            }
+
+            let mut quality = 1.0;
+            for parameter in parameters {
+                if let Some((key, value)) = parameter.split_once('=') {
+                    if key.trim().eq_ignore_ascii_case("q") {
+                        quality = value.trim().parse::<f32>().ok()?;
+                    }
+                }
+            }
+
+            if !(quality > 0.0 && quality <= 1.0) {
+                return None;
+            }
+
+            Some((range.to_owned(), quality))
+        })
+        .collect();
+
+    // Stable, so ranges of equal weight keep the order they were sent in
+    ranges.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+    ranges.into_iter().map(|(range, _)| range).collect()
+}
+
+// Maps the ranges onto `languages` rows. A range without an exact match
+// falls back to its prefixes, `pt-BR-x-foo` -> `pt-BR` -> `pt`
+pub async fn fetch_language_ids(db_pool: &MySqlPool, request: &HttpRequest) -> Result<Vec<Id>> {
+    let header = match request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|header| header.to_str().ok())
+    {
+        Some(header) => header,
+        None => return Ok(Vec::new()),
+    };
+
+    let ranges = parse(header);
+    if ranges.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let language_ids_by_code: HashMap<String, Id> = fetch_language_codes(db_pool)
+        .await?
+        .into_iter()
+        .map(|(id, code)| (code.to_ascii_lowercase(), id))
+        .collect();
+
+    Ok(match_language_ids(ranges, &language_ids_by_code))
+}
+
+fn match_language_ids(ranges: Vec<String>, language_ids_by_code: &HashMap<String, Id>) -> Vec<Id> {
+    let mut language_ids = Vec::new();
+    for range in ranges {
+        let mut range = range.to_ascii_lowercase();
+
+        let language_id = loop {
+            if let Some(language_id) = language_ids_by_code.get(&range) {
+                break Some(*language_id);
+            }
+
+            match range.rsplit_once('-') {
+                Some((prefix, _)) => range = prefix.to_owned(),
+                None => break None,
+            }
+        };
+
+        if let Some(language_id) = language_id {
+            if !language_ids.contains(&language_id) {
+                language_ids.push(language_id);
+            }
+        }
+    }
+
+    language_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_by_quality() {
+        assert_eq!(
+            parse("en;q=0.7, da, en-GB;q=0.8"),
+            vec!["da", "en-GB", "en"]
+        );
+    }
+
+    #[test]
+    fn keeps_order_of_equal_quality() {
+        assert_eq!(
+            parse("fr, de;q=0.5, it, es;q=0.5"),
+            vec!["fr", "it", "de", "es"]
+        );
+    }
+
+    #[test]
+    fn accepts_loose_formatting() {
+        assert_eq!(parse("uk ; Q = 0.9 ,en"), vec!["en", "uk"]);
+        assert_eq!(parse("en;level=1;q=0.5,de"), vec!["de", "en"]);
+    }
+
+    #[test]
+    fn drops_wildcard_zero_and_malformed_weights() {
+        assert_eq!(parse("*, en;q=0, de;q=abc, fr;q=1.5, it;q=0.1"), vec!["it"]);
+        assert!(parse("").is_empty());
+        assert!(parse(" , ,").is_empty());
+    }
+
+    #[test]
+    fn caps_amount_of_ranges() {
+        let header = vec!["en"; RANGES_MAX_AMOUNT + 10].join(",");
+
+        assert_eq!(parse(&header).len(), RANGES_MAX_AMOUNT);
+    }
+
+    #[test]
+    fn falls_back_to_prefixes() {
+        let language_ids_by_code = HashMap::from([
+            ("en".to_owned(), 1),
+            ("pt".to_owned(), 2),
+            ("pt-br".to_owned(), 3),
+        ]);
+
+        let ranges = parse("pt-BR-x-foo, pt-PT;q=0.9, en-US;q=0.8, ja;q=0.7, en;q=0.5");
+
+        assert_eq!(
+            match_language_ids(ranges, &language_ids_by_code),
+            vec![3, 2, 1]
+        );
+    }
+}
@@ -1,28 +1,61 @@
 use super::id::Id;
+use super::mention::{fetch_mentions, Mention, Target as MentionTarget};
+use super::reaction::{fetch_reactions, Reaction, Target as ReactionTarget};
 use super::user::meta::Meta as UserMeta;
 use chrono::NaiveDateTime;
 use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
 
 pub mod content;
+pub mod revision;
+pub mod thread;
 
 pub mod reply;
 use reply::Reply;
 
+// Deleted comments are kept around as tombstones,
+// so that the replies to them still make sense
+pub const DELETED_CONTENT: &'static str = "[deleted]";
+
 #[derive(Debug, Serialize)]
 pub struct Comment {
     pub id: Id,
     pub post_content_id: Id,
+    // Shown as a badge when comments from all translations are listed together
+    pub language_id: Id,
+    pub language: String,
     pub reply_to: Option<Reply>,
     pub content: String,
     pub posted_by: UserMeta,
     pub posted_at: i64,
+    pub edited_at: Option<i64>,
+    pub is_deleted: bool,
+    pub mentions: Vec<Mention>,
+    pub reactions: Vec<Reaction>,
 }
 
-impl From<RawComment> for Comment {
-    fn from(raw: RawComment) -> Self {
-        Self {
+impl Comment {
+    pub async fn fetch_from_raw(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
+        raw: RawComment,
+    ) -> Result<Self> {
+        // The tombstone doesn't mention anybody, nor can it be reacted to
+        let (mentions, reactions) = if raw.deleted_at.is_some() {
+            (Vec::new(), Vec::new())
+        } else {
+            (
+                fetch_mentions(db_pool, MentionTarget::Comment(raw.id)).await?,
+                fetch_reactions(db_pool, ReactionTarget::Comment(raw.id), fetcher_user_id).await?,
+            )
+        };
+
+        let comment = Self {
             id: raw.id,
             post_content_id: raw.post_content_id,
+            language_id: raw.language_id,
+            language: raw.language,
             reply_to: raw.reply_to_id.map(|comment_id| Reply {
                 comment_id,
                 reply_to: UserMeta {
@@ -37,14 +70,24 @@ impl From<RawComment> for Comment {
                     profile_picture_file_name: raw.reply_to_profile_picture_file_name,
                 },
             }),
-            content: raw.content,
+            content: if raw.deleted_at.is_some() {
+                DELETED_CONTENT.to_owned()
+            } else {
+                raw.content
+            },
             posted_by: UserMeta {
                 id: raw.posted_by_id,
                 handle: raw.posted_by_handle,
                 profile_picture_file_name: raw.posted_by_profile_picture_file_name,
             },
             posted_at: raw.posted_at.timestamp_millis(),
-        }
+            edited_at: raw.edited_at.map(|edited_at| edited_at.timestamp_millis()),
+            is_deleted: raw.deleted_at.is_some(),
+            mentions,
+            reactions,
+        };
+
+        Ok(comment)
     }
 }
 
@@ -52,6 +95,8 @@ impl From<RawComment> for Comment {
 pub struct RawComment {
     pub id: Id,
     pub post_content_id: Id,
+    pub language_id: Id,
+    pub language: String,
     pub reply_to_id: Option<Id>,
     pub reply_to_user_id: Option<Id>,
     pub reply_to_handle: Option<String>,
@@ -61,4 +106,6 @@ pub struct RawComment {
     pub posted_by_handle: String,
     pub posted_by_profile_picture_file_name: Option<String>,
     pub posted_at: NaiveDateTime,
+    pub edited_at: Option<NaiveDateTime>,
+    pub deleted_at: Option<NaiveDateTime>,
 }
@@ -0,0 +1,39 @@
+use crate::db::id::Id;
+use serde::Serialize;
+use sqlx::mysql::{MySql, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+// A previous version of a comment, the current one
+// is always in `comments` itself
+#[derive(Debug, Serialize)]
+pub struct Revision {
+    pub id: Id,
+    pub content: String,
+    pub written_at: i64,
+}
+
+// Keeps the current content of the comment before it gets edited
+pub async fn insert_revision(
+    tx: &mut Transaction<'_, MySql>,
+    comment_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        INSERT INTO comment_revisions
+        (
+            comment_id,
+            content,
+            written_at
+        )
+        SELECT
+            id,
+            content,
+            COALESCE(edited_at, posted_at)
+        FROM comments
+        WHERE id = ?
+        ",
+        comment_id
+    )
+    .execute(&mut *tx)
+    .await
+}
@@ -0,0 +1,281 @@
+use super::{Comment, RawComment};
+use crate::db::id::Id;
+use crate::db::page::Page;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::{QueryBuilder, Result};
+
+// How many replies come along with every comment,
+// the rest is fetched by expanding the comment
+pub const PREVIEW_REPLIES_AMOUNT: u64 = 3;
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(tag = "tag")]
+pub enum Sort {
+    Newest,
+    Oldest,
+    MostReacted,
+}
+
+impl Sort {
+    fn order_by(&self) -> &'static str {
+        match self {
+            Self::Newest => "comments.posted_at DESC",
+            Self::Oldest => "comments.posted_at ASC",
+            Self::MostReacted => {
+                "
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM reactions
+                    WHERE reactions.comment_id = comments.id
+                ) DESC,
+                comments.posted_at DESC
+                "
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Thread {
+    pub comment: Comment,
+    // Direct replies only, including the ones not in `replies`
+    pub reply_count: i64,
+    // Previews have no replies of their own, only a `reply_count`
+    pub replies: Vec<Thread>,
+}
+
+impl Thread {
+    // Top level comments of a post content, or of every
+    // translation of its post if `all_languages` is set
+    pub async fn fetch_page(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
+        post_content_id: Id,
+        all_languages: bool,
+        sort: Sort,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        let raw_threads =
+            RawThread::fetch_page(db_pool, post_content_id, all_languages, sort, page).await?;
+
+        Self::fetch_with_previews(db_pool, fetcher_user_id, raw_threads).await
+    }
+
+    // Replies are always in the order they were posted,
+    // so that the conversation reads top to bottom.
+    // They can come from other translations of the post
+    // than the comment they reply to
+    pub async fn fetch_replies_page(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
+        comment_id: Id,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        let raw_threads = RawThread::fetch_replies_page(db_pool, comment_id, page).await?;
+
+        Self::fetch_with_previews(db_pool, fetcher_user_id, raw_threads).await
+    }
+
+    async fn fetch_with_previews(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
+        raw_threads: Vec<RawThread>,
+    ) -> Result<Vec<Self>> {
+        let preview_page = Page::new(PREVIEW_REPLIES_AMOUNT, 0);
+
+        let mut threads = Vec::new();
+        for raw_thread in raw_threads {
+            let mut replies = Vec::new();
+            if raw_thread.reply_count != 0 {
+                for raw_reply in
+                    RawThread::fetch_replies_page(db_pool, raw_thread.id, preview_page).await?
+                {
+                    replies.push(Self::fetch_from_raw(db_pool, fetcher_user_id, raw_reply).await?);
+                }
+            }
+
+            let mut thread = Self::fetch_from_raw(db_pool, fetcher_user_id, raw_thread).await?;
+            thread.replies = replies;
+
+            threads.push(thread);
+        }
+
+        Ok(threads)
+    }
+
+    async fn fetch_from_raw(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
+        raw: RawThread,
+    ) -> Result<Self> {
+        let reply_count = raw.reply_count;
+
+        let comment = RawComment {
+            id: raw.id,
+            post_content_id: raw.post_content_id,
+            language_id: raw.language_id,
+            language: raw.language,
+            reply_to_id: raw.reply_to_id,
+            reply_to_user_id: raw.reply_to_user_id,
+            reply_to_handle: raw.reply_to_handle,
+            reply_to_profile_picture_file_name: raw.reply_to_profile_picture_file_name,
+            content: raw.content,
+            posted_by_id: raw.posted_by_id,
+            posted_by_handle: raw.posted_by_handle,
+            posted_by_profile_picture_file_name: raw.posted_by_profile_picture_file_name,
+            posted_at: raw.posted_at,
+            edited_at: raw.edited_at,
+            deleted_at: raw.deleted_at,
+        };
+
+        let thread = Self {
+            comment: Comment::fetch_from_raw(db_pool, fetcher_user_id, comment).await?,
+            reply_count,
+            replies: Vec::new(),
+        };
+
+        Ok(thread)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct RawThread {
+    id: Id,
+    post_content_id: Id,
+    language_id: Id,
+    language: String,
+    reply_to_id: Option<Id>,
+    reply_to_user_id: Option<Id>,
+    reply_to_handle: Option<String>,
+    reply_to_profile_picture_file_name: Option<String>,
+    content: String,
+    posted_by_id: Id,
+    posted_by_handle: String,
+    posted_by_profile_picture_file_name: Option<String>,
+    posted_at: NaiveDateTime,
+    edited_at: Option<NaiveDateTime>,
+    deleted_at: Option<NaiveDateTime>,
+    reply_count: i64,
+}
+
+// Every way of fetching threads selects the same columns
+// and hides the same comments, they only filter and sort differently
+const SELECT_THREADS: &str = "
+    SELECT
+        comments.id,
+        comments.post_content_id,
+        post_contents.language_id,
+        languages.name AS language,
+        comments.reply_to AS reply_to_id,
+        reply_to_users.id AS reply_to_user_id,
+        reply_to_users.handle AS reply_to_handle,
+        CONCAT(reply_to_files.id, \".\", reply_to_files.extension) AS reply_to_profile_picture_file_name,
+        comments.content,
+        posted_by_users.id AS posted_by_id,
+        posted_by_users.handle AS posted_by_handle,
+        CONCAT(posted_by_files.id, \".\", posted_by_files.extension) AS posted_by_profile_picture_file_name,
+        comments.posted_at,
+        comments.edited_at,
+        comments.deleted_at,
+        (
+            SELECT
+                COUNT(*)
+            FROM comments AS replies
+            WHERE
+                replies.reply_to = comments.id
+                AND (
+                    replies.deleted_at IS NULL
+                    OR EXISTS (
+                        SELECT
+                            id
+                        FROM comments AS reply_replies
+                        WHERE reply_replies.reply_to = replies.id
+                    )
+                )
+        ) AS reply_count
+    FROM comments
+        JOIN post_contents ON comments.post_content_id = post_contents.id
+        JOIN posts ON post_contents.post_id = posts.id
+        JOIN languages ON post_contents.language_id = languages.id
+        LEFT JOIN comments AS reply_to ON comments.reply_to = reply_to.id
+        LEFT JOIN users AS reply_to_users ON reply_to.posted_by = reply_to_users.id
+        LEFT JOIN files AS reply_to_files ON reply_to_users.profile_picture_file_id = reply_to_files.id
+        JOIN users AS posted_by_users ON comments.posted_by = posted_by_users.id
+        LEFT JOIN files AS posted_by_files ON posted_by_users.profile_picture_file_id = posted_by_files.id
+    WHERE
+        (
+            comments.deleted_at IS NULL
+            OR EXISTS (
+                SELECT
+                    id
+                FROM comments AS replies
+                WHERE replies.reply_to = comments.id
+            )
+        )
+        AND posts.deleted_at IS NULL
+        AND post_contents.deleted_at IS NULL
+";
+
+// Deleted comments nobody replied to are left out,
+// the tombstones are only there to keep threads together
+impl RawThread {
+    async fn fetch_page(
+        db_pool: &MySqlPool,
+        post_content_id: Id,
+        all_languages: bool,
+        sort: Sort,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        let mut query_builder = QueryBuilder::<MySql>::new(SELECT_THREADS);
+
+        // The viewed content only picks the post,
+        // unless the comments are limited to its language
+        query_builder
+            .push(" AND post_contents.post_id = (SELECT post_id FROM post_contents AS viewed_post_contents WHERE viewed_post_contents.id = ")
+            .push_bind(post_content_id)
+            .push(") AND (")
+            .push_bind(all_languages)
+            .push(" OR comments.post_content_id = ")
+            .push_bind(post_content_id)
+            .push(") AND comments.reply_to IS NULL ORDER BY ")
+            .push(sort.order_by());
+
+        Self::push_page(&mut query_builder, page);
+
+        query_builder
+            .build_query_as::<Self>()
+            .fetch_all(db_pool)
+            .await
+    }
+
+    async fn fetch_replies_page(
+        db_pool: &MySqlPool,
+        comment_id: Id,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        let mut query_builder = QueryBuilder::<MySql>::new(SELECT_THREADS);
+
+        query_builder
+            .push(" AND comments.reply_to = ")
+            .push_bind(comment_id)
+            .push(" ORDER BY comments.posted_at ASC");
+
+        Self::push_page(&mut query_builder, page);
+
+        query_builder
+            .build_query_as::<Self>()
+            .fetch_all(db_pool)
+            .await
+    }
+
+    fn push_page(query_builder: &mut QueryBuilder<'_, MySql>, page: Page) {
+        query_builder
+            .push(" LIMIT ")
+            .push_bind(page.get_limit())
+            .push(" OFFSET ")
+            .push_bind(page.get_offset());
+    }
+}
@@ -0,0 +1,148 @@
+use super::id::Id;
+use crate::text::word;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+pub mod scope;
+use scope::Scope;
+
+pub mod term;
+
+#[derive(Debug, Serialize)]
+pub struct Entry {
+    pub id: Id,
+    pub scope: Scope,
+    pub term: String,
+    pub translation: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Warning {
+    pub term: String,
+    pub translation: String,
+}
+
+impl Entry {
+    // Terms of the post itself, of its author and site wide ones,
+    // that are written in the language of the post's source content
+    // and have a translation into `language_id`.
+    // The most specific scope wins when a term is defined more than once
+    pub async fn fetch_applicable(
+        db_pool: &MySqlPool,
+        post_id: Id,
+        language_id: Id,
+    ) -> Result<Vec<Self>> {
+        #[derive(Debug)]
+        struct RawEntry {
+            id: Id,
+            scope: String,
+            term: String,
+            translation: String,
+        }
+
+        let raw_entries = sqlx::query_as!(
+            RawEntry,
+            "
+            SELECT
+                glossary_terms.id,
+                glossary_terms.scope,
+                glossary_terms.term,
+                glossary_term_translations.translation
+            FROM glossary_terms
+                JOIN glossary_term_translations ON glossary_terms.id = glossary_term_translations.glossary_term_id
+                JOIN posts ON posts.id = ?
+                JOIN post_contents AS source_post_contents ON source_post_contents.id = posts.source_post_content_id
+            WHERE
+                posts.deleted_at IS NULL
+                AND glossary_term_translations.language_id = ?
+                AND glossary_terms.language_id = source_post_contents.language_id
+                AND (
+                    (glossary_terms.scope = ? AND glossary_terms.post_id = posts.id)
+                    OR (glossary_terms.scope = ? AND glossary_terms.author_id = posts.posted_by)
+                    OR glossary_terms.scope = ?
+                )
+            ORDER BY FIELD(glossary_terms.scope, ?, ?, ?), glossary_terms.term
+            ",
+            post_id,
+            language_id,
+            Scope::Post.as_str(),
+            Scope::Author.as_str(),
+            Scope::Site.as_str(),
+            Scope::Post.as_str(),
+            Scope::Author.as_str(),
+            Scope::Site.as_str()
+        )
+        .fetch_all(db_pool)
+        .await?;
+
+        let mut entries: Vec<Self> = Vec::new();
+        for raw in raw_entries {
+            let term = raw.term.to_lowercase();
+
+            if entries
+                .iter()
+                .any(|entry| entry.term.to_lowercase() == term)
+            {
+                continue;
+            }
+
+            entries.push(Self {
+                id: raw.id,
+                // TODO: Better error handling
+                scope: Scope::from_str(&raw.scope).unwrap(),
+                term: raw.term,
+                translation: raw.translation,
+            });
+        }
+
+        Ok(entries)
+    }
+}
+
+// Reports every glossary term that appears in the source content
+// of the post while its translation is missing from `content`
+pub async fn check(
+    db_pool: &MySqlPool,
+    post_id: Id,
+    language_id: Id,
+    content: &str,
+) -> Result<Vec<Warning>> {
+    let source = match sqlx::query!(
+        "
+        SELECT
+            source_post_contents.language_id,
+            source_post_contents.content
+        FROM posts
+            JOIN post_contents AS source_post_contents ON source_post_contents.id = posts.source_post_content_id
+        WHERE posts.id = ?
+        ",
+        post_id
+    )
+    .fetch_optional(db_pool)
+    .await?
+    {
+        Some(source) => source,
+        None => return Ok(Vec::new()),
+    };
+
+    // Nothing to check the source content against
+    if source.language_id == language_id {
+        return Ok(Vec::new());
+    }
+
+    let warnings = Entry::fetch_applicable(db_pool, post_id, language_id)
+        .await?
+        .into_iter()
+        .filter(|entry| {
+            word::contains(&source.content, &entry.term)
+                && !word::contains(content, &entry.translation)
+        })
+        .map(|entry| Warning {
+            term: entry.term,
+            translation: entry.translation,
+        })
+        .collect();
+
+    Ok(warnings)
+}
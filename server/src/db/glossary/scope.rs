@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+pub const SCOPE_POST: &'static str = "Post";
+pub const SCOPE_AUTHOR: &'static str = "Author";
+pub const SCOPE_SITE: &'static str = "Site";
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, sqlx::Type)]
+#[serde(tag = "tag")]
+pub enum Scope {
+    Post,
+    Author,
+    Site,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Invalid,
+}
+
+impl Scope {
+    pub fn from_str(scope: &str) -> Result<Self, Error> {
+        match scope {
+            SCOPE_POST => Ok(Self::Post),
+            SCOPE_AUTHOR => Ok(Self::Author),
+            SCOPE_SITE => Ok(Self::Site),
+            _ => Err(Error::Invalid),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Post => SCOPE_POST,
+            Self::Author => SCOPE_AUTHOR,
+            Self::Site => SCOPE_SITE,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Empty,
+    TooLong,
+}
+
+// Used for both the term and its translations
+#[derive(Debug)]
+pub struct Term(String);
+
+impl AsRef<str> for Term {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Into<String> for Term {
+    fn into(self) -> String {
+        self.0
+    }
+}
+
+impl Term {
+    pub fn parse(term: String) -> Result<Self, Error> {
+        let term = term.trim().to_owned();
+
+        Self::validate(&term)?;
+
+        Ok(Self(term))
+    }
+
+    pub fn max_char_count() -> usize {
+        128
+    }
+
+    fn validate(term: &str) -> Result<(), Error> {
+        if term.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if term.chars().count() > Self::max_char_count() {
+            return Err(Error::TooLong);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Empty,
+    TooLong,
+    Invalid,
+}
+
+// BCP-47 language tag like `en`, `pt-BR` or `sr-Latn`.
+// Subtags are normalized to their conventional case,
+// so that `PT-br` and `pt-BR` are the same code
+#[derive(Debug)]
+pub struct Code(String);
+
+impl AsRef<str> for Code {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Into<String> for Code {
+    fn into(self) -> String {
+        self.0
+    }
+}
+
+impl Code {
+    pub fn parse(code: String) -> Result<Self, Error> {
+        Self::validate(&code)?;
+
+        Ok(Self(Self::normalize(&code)))
+    }
+
+    pub fn max_char_count() -> usize {
+        16
+    }
+
+    fn validate(code: &str) -> Result<(), Error> {
+        if code.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if code.chars().count() > Self::max_char_count() {
+            return Err(Error::TooLong);
+        }
+
+        let mut subtags = code.split('-');
+
+        let language = subtags
+            .next()
+            .expect("`split` always yields at least one item");
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return Err(Error::Invalid);
+        }
+
+        // Script, region and variant subtags,
+        // each of them can only follow the previous ones
+        let mut position = 0;
+        for subtag in subtags {
+            let subtag_position = if position < 1 && is_script(subtag) {
+                1
+            } else if position < 2 && is_region(subtag) {
+                2
+            } else if is_variant(subtag) {
+                3
+            } else {
+                return Err(Error::Invalid);
+            };
+
+            position = subtag_position;
+        }
+
+        Ok(())
+    }
+
+    fn normalize(code: &str) -> String {
+        code.split('-')
+            .enumerate()
+            .map(|(i, subtag)| {
+                if i != 0 && is_script(subtag) {
+                    let mut chars = subtag.chars();
+                    let first = chars
+                        .next()
+                        .expect("Scripts are exactly 4 chars long")
+                        .to_ascii_uppercase();
+
+                    std::iter::once(first)
+                        .chain(chars.map(|c| c.to_ascii_lowercase()))
+                        .collect()
+                } else if i != 0 && is_region(subtag) {
+                    subtag.to_ascii_uppercase()
+                } else {
+                    subtag.to_ascii_lowercase()
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("-")
+    }
+}
+
+fn is_script(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_region(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_variant(subtag: &str) -> bool {
+    let is_alphanumeric = subtag.chars().all(|c| c.is_ascii_alphanumeric());
+
+    ((5..=8).contains(&subtag.len()) && is_alphanumeric)
+        || (subtag.len() == 4
+            && is_alphanumeric
+            && subtag.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(code: &str) -> Result<String, Error> {
+        Code::parse(code.to_owned()).map(|code| code.into())
+    }
+
+    #[test]
+    fn accepts_language_only() {
+        assert_eq!(parse("en").unwrap(), "en");
+        assert_eq!(parse("fil").unwrap(), "fil");
+    }
+
+    #[test]
+    fn normalizes_case() {
+        assert_eq!(parse("PT-br").unwrap(), "pt-BR");
+        assert_eq!(parse("SR-LATN").unwrap(), "sr-Latn");
+        assert_eq!(parse("zh-hant-tw").unwrap(), "zh-Hant-TW");
+        assert_eq!(parse("ES-419").unwrap(), "es-419");
+    }
+
+    #[test]
+    fn accepts_variants() {
+        assert_eq!(parse("de-CH-1901").unwrap(), "de-CH-1901");
+        assert_eq!(parse("sl-ROZAJ").unwrap(), "sl-rozaj");
+    }
+
+    #[test]
+    fn rejects_empty_and_long_codes() {
+        assert!(matches!(parse(""), Err(Error::Empty)));
+        assert!(matches!(parse("en-Latn-US-variant1"), Err(Error::TooLong)));
+    }
+
+    #[test]
+    fn rejects_malformed_codes() {
+        assert!(matches!(parse("e"), Err(Error::Invalid)));
+        assert!(matches!(parse("engl"), Err(Error::Invalid)));
+        assert!(matches!(parse("e1"), Err(Error::Invalid)));
+        assert!(matches!(parse("en-"), Err(Error::Invalid)));
+        assert!(matches!(parse("en_US"), Err(Error::Invalid)));
+        assert!(matches!(parse("en-%"), Err(Error::Invalid)));
+    }
+
+    #[test]
+    fn rejects_subtags_out_of_order() {
+        assert!(matches!(parse("en-US-Latn"), Err(Error::Invalid)));
+        assert!(matches!(parse("sr-Latn-Cyrl"), Err(Error::Invalid)));
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+pub const DIRECTION_LTR: &'static str = "Ltr";
+pub const DIRECTION_RTL: &'static str = "Rtl";
+
+// Which way the text of a language is written
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, sqlx::Type)]
+#[serde(tag = "tag")]
+pub enum Direction {
+    Ltr,
+    Rtl,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Invalid,
+}
+
+impl Direction {
+    pub fn from_str(direction: &str) -> Result<Self, Error> {
+        match direction {
+            DIRECTION_LTR => Ok(Self::Ltr),
+            DIRECTION_RTL => Ok(Self::Rtl),
+            _ => Err(Error::Invalid),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ltr => DIRECTION_LTR,
+            Self::Rtl => DIRECTION_RTL,
+        }
+    }
+}
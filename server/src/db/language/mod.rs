@@ -0,0 +1,237 @@
+use super::id::{Id, IdRow};
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+use std::collections::{HashMap, HashSet};
+
+pub mod code;
+pub mod direction;
+pub mod name;
+pub mod script;
+
+use direction::Direction;
+
+#[derive(Debug, Serialize)]
+pub struct Language {
+    pub id: Id,
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub script: Option<String>,
+    pub direction: Direction,
+    // The language to fall back to, `pt-BR` -> `pt`
+    pub parent_id: Option<Id>,
+}
+
+impl From<RawLanguage> for Language {
+    fn from(raw: RawLanguage) -> Self {
+        Self {
+            id: raw.id,
+            code: raw.code,
+            name: raw.name,
+            native_name: raw.native_name,
+            script: raw.script,
+            // TODO: Better error handling
+            direction: Direction::from_str(&raw.direction).unwrap(),
+            parent_id: raw.parent_id,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RawLanguage {
+    pub id: Id,
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub script: Option<String>,
+    pub direction: String,
+    pub parent_id: Option<Id>,
+}
+
+pub async fn fetch_user_language_ids(db_pool: &MySqlPool, user_id: Id) -> Result<Vec<Id>> {
+    sqlx::query_as!(
+        IdRow,
+        "
+        SELECT
+            language_id AS id
+        FROM user_languages
+        WHERE user_id = ?
+        ",
+        user_id
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|id_rows| id_rows.into_iter().map(|id_row| id_row.id).collect())
+}
+
+// Follows the fallback chain of every language right after it,
+// so `[pt-BR, en]` becomes `[pt-BR, pt, en]`.
+// Languages keep the position they were first seen at
+pub async fn expand_fallbacks(db_pool: &MySqlPool, language_ids: &[Id]) -> Result<Vec<Id>> {
+    let parent_ids = fetch_parent_ids(db_pool).await?;
+
+    Ok(follow_fallbacks(language_ids, &parent_ids))
+}
+
+fn follow_fallbacks(language_ids: &[Id], parent_ids: &HashMap<Id, Option<Id>>) -> Vec<Id> {
+    let mut seen_language_ids = HashSet::new();
+    let mut expanded_language_ids = Vec::new();
+    for language_id in language_ids {
+        let mut language_id = Some(*language_id);
+
+        // A language that was already seen also had its chain followed
+        while let Some(id) = language_id {
+            if !seen_language_ids.insert(id) {
+                break;
+            }
+
+            expanded_language_ids.push(id);
+            language_id = parent_ids.get(&id).copied().flatten();
+        }
+    }
+
+    expanded_language_ids
+}
+
+pub async fn fetch_language_code(db_pool: &MySqlPool, language_id: Id) -> Result<Option<String>> {
+    sqlx::query!(
+        "
+        SELECT
+            code
+        FROM languages
+        WHERE id = ?
+        ",
+        language_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.code))
+}
+
+// Prefers the exact code, `pt` over `pt-BR`,
+// but settles for a regional one otherwise
+pub async fn fetch_language_by_code(db_pool: &MySqlPool, code: &str) -> Result<Option<Language>> {
+    sqlx::query_as!(
+        RawLanguage,
+        "
+        SELECT
+            id,
+            code,
+            name,
+            native_name,
+            script,
+            direction,
+            parent_id
+        FROM languages
+        WHERE
+            code = ?
+            OR LEFT(code, CHAR_LENGTH(?) + 1) = CONCAT(?, '-')
+        ORDER BY code = ? DESC, id
+        LIMIT 1
+        ",
+        code,
+        code,
+        code,
+        code
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|language| language.into()))
+}
+
+pub async fn fetch_language_codes(db_pool: &MySqlPool) -> Result<Vec<(Id, String)>> {
+    sqlx::query!(
+        "
+        SELECT
+            id,
+            code
+        FROM languages
+        "
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|rows| rows.into_iter().map(|row| (row.id, row.code)).collect())
+}
+
+// The fallback chain of a language, starting with its parent
+pub async fn fetch_ancestor_ids(db_pool: &MySqlPool, language_id: Id) -> Result<Vec<Id>> {
+    let parent_ids = fetch_parent_ids(db_pool).await?;
+
+    let mut seen_language_ids = HashSet::from([language_id]);
+    let mut ancestor_ids = Vec::new();
+    let mut parent_id = parent_ids.get(&language_id).copied().flatten();
+    while let Some(id) = parent_id {
+        if !seen_language_ids.insert(id) {
+            break;
+        }
+
+        ancestor_ids.push(id);
+        parent_id = parent_ids.get(&id).copied().flatten();
+    }
+
+    Ok(ancestor_ids)
+}
+
+async fn fetch_parent_ids(db_pool: &MySqlPool) -> Result<HashMap<Id, Option<Id>>> {
+    sqlx::query!(
+        "
+        SELECT
+            id,
+            parent_id
+        FROM languages
+        "
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|rows| {
+        rows.into_iter()
+            .map(|row| (row.id, row.parent_id))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1 `en`, 2 `pt`, 3 `pt-BR` -> `pt`, 4 `sr`, 5 `sr-Latn` -> `sr`, 6 `sr-Latn-ME` -> `sr-Latn`
+    fn parent_ids() -> HashMap<Id, Option<Id>> {
+        HashMap::from([
+            (1, None),
+            (2, None),
+            (3, Some(2)),
+            (4, None),
+            (5, Some(4)),
+            (6, Some(5)),
+        ])
+    }
+
+    #[test]
+    fn follows_fallbacks_right_after_language() {
+        assert_eq!(follow_fallbacks(&[3, 1], &parent_ids()), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn follows_whole_chain() {
+        assert_eq!(follow_fallbacks(&[6], &parent_ids()), vec![6, 5, 4]);
+    }
+
+    #[test]
+    fn keeps_first_position() {
+        assert_eq!(follow_fallbacks(&[2, 3], &parent_ids()), vec![2, 3]);
+        assert_eq!(follow_fallbacks(&[3, 2, 1], &parent_ids()), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn stops_at_cycles() {
+        let parent_ids = HashMap::from([(1, Some(2)), (2, Some(1))]);
+
+        assert_eq!(follow_fallbacks(&[1], &parent_ids), vec![1, 2]);
+    }
+
+    #[test]
+    fn keeps_unknown_languages() {
+        assert_eq!(follow_fallbacks(&[42], &parent_ids()), vec![42]);
+    }
+}
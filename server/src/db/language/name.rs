@@ -0,0 +1,45 @@
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Empty,
+    TooLong,
+}
+
+// Used both for the english and the native name of a language
+#[derive(Debug)]
+pub struct Name(String);
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Into<String> for Name {
+    fn into(self) -> String {
+        self.0
+    }
+}
+
+impl Name {
+    pub fn parse(name: String) -> Result<Self, Error> {
+        Self::validate(&name)?;
+
+        Ok(Self(name))
+    }
+
+    pub fn max_char_count() -> usize {
+        128
+    }
+
+    fn validate(name: &str) -> Result<(), Error> {
+        if name.trim().is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if name.chars().count() > Self::max_char_count() {
+            return Err(Error::TooLong);
+        }
+
+        Ok(())
+    }
+}
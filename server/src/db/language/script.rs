@@ -0,0 +1,48 @@
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Invalid,
+}
+
+// ISO 15924 script code like `Latn` or `Cyrl`
+#[derive(Debug)]
+pub struct Script(String);
+
+impl AsRef<str> for Script {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Into<String> for Script {
+    fn into(self) -> String {
+        self.0
+    }
+}
+
+impl Script {
+    pub fn parse(script: String) -> Result<Self, Error> {
+        Self::validate(&script)?;
+
+        let script = script
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if i == 0 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c.to_ascii_lowercase()
+                }
+            })
+            .collect();
+
+        Ok(Self(script))
+    }
+
+    fn validate(script: &str) -> Result<(), Error> {
+        if script.len() != 4 || !script.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(Error::Invalid);
+        }
+
+        Ok(())
+    }
+}
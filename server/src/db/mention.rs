@@ -0,0 +1,440 @@
+use crate::db::id::Id;
+use crate::db::notification::kind::Kind as NotificationKind;
+use crate::db::notification::notify;
+use crate::db::user::handle::{Handle, HANDLE_CHARS};
+use crate::db::user::meta::Meta as UserMeta;
+use crate::realtime::Event;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Serialize;
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::{QueryBuilder, Result, Transaction};
+use std::collections::{HashMap, HashSet};
+
+// Only the first this many mentions of a post or comment are linked
+pub const MENTIONS_MAX_AMOUNT: usize = 50;
+
+// A post or comment only notifies this many distinct users,
+// the rest are still linked but not bothered
+pub const MENTIONS_NOTIFY_MAX_AMOUNT: usize = 10;
+
+lazy_static! {
+    static ref MENTION_REGEX: Regex =
+        Regex::new(&format!("@([{HANDLE_CHARS}]+)")).expect("Failed to compile mention regex");
+    static ref PRECEDING_REGEX: Regex =
+        Regex::new(&format!("^[{HANDLE_CHARS}@]$")).expect("Failed to compile mention regex");
+}
+
+// Offsets are in chars, not bytes, and cover the `@` too
+#[derive(Debug, Serialize)]
+pub struct Mention {
+    pub start: usize,
+    pub end: usize,
+    pub user: UserMeta,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Target {
+    Comment(Id),
+    PostContent(Id),
+}
+
+#[derive(Debug)]
+struct Span<'a> {
+    start: usize,
+    end: usize,
+    handle: &'a str,
+}
+
+// `@handle` not preceded by a handle char, so that
+// e-mail addresses like `name@example.com` are left alone
+fn find_spans(text: &str) -> Vec<Span<'_>> {
+    // Char offsets are counted as the matches go, so the text is only walked once
+    let mut byte_offset = 0;
+    let mut char_offset = 0;
+
+    MENTION_REGEX
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let mention = captures.get(0).expect("Group 0 is always there");
+            let handle = captures.get(1).expect("Group 1 is not optional");
+
+            let is_preceded = match text[..mention.start()].chars().next_back() {
+                Some(c) => PRECEDING_REGEX.is_match(c.encode_utf8(&mut [0; 4])),
+                None => false,
+            };
+
+            if is_preceded {
+                return None;
+            }
+
+            if Handle::parse(handle.as_str().to_owned()).is_err() {
+                return None;
+            }
+
+            char_offset += text[byte_offset..mention.start()].chars().count();
+            byte_offset = mention.start();
+
+            let start = char_offset;
+            let end = start + mention.as_str().chars().count();
+
+            Some(Span {
+                start,
+                end,
+                handle: handle.as_str(),
+            })
+        })
+        .collect()
+}
+
+// Resolves the mentions in `content` anew and notifies
+// the users that weren't mentioned there before
+pub async fn update_mentions(
+    tx: &mut Transaction<'_, MySql>,
+    target: Target,
+    content: &str,
+    mentioned_by: Id,
+) -> Result<Vec<Event>> {
+    let previous_user_ids: HashSet<Id> = fetch_mentioned_user_ids(tx, target)
+        .await?
+        .into_iter()
+        .collect();
+
+    delete_mentions(tx, target).await?;
+
+    let spans: Vec<Span> = find_spans(content)
+        .into_iter()
+        .take(MENTIONS_MAX_AMOUNT)
+        .collect();
+
+    let handles = spans
+        .iter()
+        .map(|span| span.handle.to_ascii_lowercase())
+        .collect();
+    let user_ids_by_handle = fetch_user_ids_by_handle(tx, &handles).await?;
+
+    let mentions: Vec<(Id, &Span)> = spans
+        .iter()
+        .filter_map(|span| {
+            user_ids_by_handle
+                .get(&span.handle.to_ascii_lowercase())
+                .map(|user_id| (*user_id, span))
+        })
+        .collect();
+
+    insert_mentions(tx, target, &mentions).await?;
+
+    let mut events = Vec::new();
+    let mut notified_user_ids = HashSet::new();
+    for (user_id, _) in mentions {
+        if !previous_user_ids.contains(&user_id)
+            && notified_user_ids.len() < MENTIONS_NOTIFY_MAX_AMOUNT
+            && notified_user_ids.insert(user_id)
+        {
+            let (post_content_id, comment_id) = match target {
+                Target::Comment(comment_id) => (
+                    fetch_comment_post_content_id(tx, comment_id).await?,
+                    Some(comment_id),
+                ),
+                Target::PostContent(post_content_id) => (Some(post_content_id), None),
+            };
+
+            let event = notify(
+                tx,
+                user_id,
+                NotificationKind::Mention,
+                mentioned_by,
+                post_content_id,
+                comment_id,
+            )
+            .await?;
+
+            events.extend(event);
+        }
+    }
+
+    Ok(events)
+}
+
+pub async fn fetch_mentions(db_pool: &MySqlPool, target: Target) -> Result<Vec<Mention>> {
+    #[derive(Debug)]
+    struct RawMention {
+        start_offset: i32,
+        end_offset: i32,
+        user_id: Id,
+        handle: String,
+        profile_picture_file_name: Option<String>,
+    }
+
+    let raw_mentions = match target {
+        Target::Comment(comment_id) => {
+            sqlx::query_as!(
+                RawMention,
+                "
+                SELECT
+                    mentions.start_offset,
+                    mentions.end_offset,
+                    users.id AS user_id,
+                    users.handle,
+                    CONCAT(files.id, \".\", files.extension) AS profile_picture_file_name
+                FROM mentions
+                    JOIN users ON mentions.user_id = users.id
+                    LEFT JOIN files ON users.profile_picture_file_id = files.id
+                WHERE mentions.comment_id = ?
+                ORDER BY mentions.start_offset
+                ",
+                comment_id
+            )
+            .fetch_all(db_pool)
+            .await?
+        }
+        Target::PostContent(post_content_id) => {
+            sqlx::query_as!(
+                RawMention,
+                "
+                SELECT
+                    mentions.start_offset,
+                    mentions.end_offset,
+                    users.id AS user_id,
+                    users.handle,
+                    CONCAT(files.id, \".\", files.extension) AS profile_picture_file_name
+                FROM mentions
+                    JOIN users ON mentions.user_id = users.id
+                    LEFT JOIN files ON users.profile_picture_file_id = files.id
+                WHERE mentions.post_content_id = ?
+                ORDER BY mentions.start_offset
+                ",
+                post_content_id
+            )
+            .fetch_all(db_pool)
+            .await?
+        }
+    };
+
+    let mentions = raw_mentions
+        .into_iter()
+        .map(|raw| Mention {
+            start: raw.start_offset as usize,
+            end: raw.end_offset as usize,
+            user: UserMeta {
+                id: raw.user_id,
+                handle: raw.handle,
+                profile_picture_file_name: raw.profile_picture_file_name,
+            },
+        })
+        .collect();
+
+    Ok(mentions)
+}
+
+// Keyed by lowercase handle, since handles are compared case-insensitively
+async fn fetch_user_ids_by_handle(
+    tx: &mut Transaction<'_, MySql>,
+    handles: &HashSet<String>,
+) -> Result<HashMap<String, Id>> {
+    if handles.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mut query_builder = QueryBuilder::<MySql>::new(
+        "
+        SELECT
+            id,
+            handle
+        FROM users
+        WHERE handle IN (
+        ",
+    );
+
+    let mut separated = query_builder.separated(", ");
+    for handle in handles {
+        separated.push_bind(handle.clone());
+    }
+    separated.push_unseparated(")");
+
+    let rows = query_builder
+        .build_query_as::<(Id, String)>()
+        .fetch_all(&mut *tx)
+        .await?;
+
+    let user_ids_by_handle = rows
+        .into_iter()
+        .map(|(id, handle)| (handle.to_ascii_lowercase(), id))
+        .collect();
+
+    Ok(user_ids_by_handle)
+}
+
+async fn fetch_comment_post_content_id(
+    tx: &mut Transaction<'_, MySql>,
+    comment_id: Id,
+) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            post_content_id
+        FROM comments
+        WHERE id = ?
+        ",
+        comment_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.map(|row| row.post_content_id))
+}
+
+async fn fetch_mentioned_user_ids(
+    tx: &mut Transaction<'_, MySql>,
+    target: Target,
+) -> Result<Vec<Id>> {
+    match target {
+        Target::Comment(comment_id) => sqlx::query!(
+            "
+            SELECT
+                user_id
+            FROM mentions
+            WHERE comment_id = ?
+            ",
+            comment_id
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map(|rows| rows.into_iter().map(|row| row.user_id).collect()),
+        Target::PostContent(post_content_id) => sqlx::query!(
+            "
+            SELECT
+                user_id
+            FROM mentions
+            WHERE post_content_id = ?
+            ",
+            post_content_id
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map(|rows| rows.into_iter().map(|row| row.user_id).collect()),
+    }
+}
+
+async fn delete_mentions(tx: &mut Transaction<'_, MySql>, target: Target) -> Result<()> {
+    match target {
+        Target::Comment(comment_id) => {
+            sqlx::query!(
+                "
+            DELETE FROM mentions
+            WHERE comment_id = ?
+            ",
+                comment_id
+            )
+            .execute(&mut *tx)
+            .await?
+        }
+        Target::PostContent(post_content_id) => {
+            sqlx::query!(
+                "
+            DELETE FROM mentions
+            WHERE post_content_id = ?
+            ",
+                post_content_id
+            )
+            .execute(&mut *tx)
+            .await?
+        }
+    };
+
+    Ok(())
+}
+
+async fn insert_mentions(
+    tx: &mut Transaction<'_, MySql>,
+    target: Target,
+    mentions: &[(Id, &Span<'_>)],
+) -> Result<()> {
+    if mentions.is_empty() {
+        return Ok(());
+    }
+
+    let (comment_id, post_content_id) = match target {
+        Target::Comment(comment_id) => (Some(comment_id), None),
+        Target::PostContent(post_content_id) => (None, Some(post_content_id)),
+    };
+
+    let mut query_builder = QueryBuilder::<MySql>::new(
+        "
+        INSERT INTO mentions
+        (
+            user_id,
+            comment_id,
+            post_content_id,
+            start_offset,
+            end_offset
+        )
+        ",
+    );
+
+    query_builder.push_values(mentions, |mut row, (user_id, span)| {
+        row.push_bind(*user_id)
+            .push_bind(comment_id)
+            .push_bind(post_content_id)
+            .push_bind(span.start as u32)
+            .push_bind(span.end as u32);
+    });
+
+    query_builder.build().execute(&mut *tx).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_spans;
+
+    fn spans(text: &str) -> Vec<(usize, usize, &str)> {
+        find_spans(text)
+            .into_iter()
+            .map(|span| (span.start, span.end, span.handle))
+            .collect()
+    }
+
+    #[test]
+    fn finds_mentions() {
+        assert_eq!(
+            spans("@alice meet @bob_2."),
+            vec![(0, 6, "alice"), (12, 18, "bob_2")]
+        );
+    }
+
+    #[test]
+    fn ignores_email_addresses() {
+        assert_eq!(spans("write to name@example.com"), vec![]);
+        assert_eq!(
+            spans("first-last@example.com or @alice"),
+            vec![(26, 32, "alice")]
+        );
+    }
+
+    #[test]
+    fn ignores_doubled_at() {
+        assert_eq!(spans("@@alice"), vec![]);
+    }
+
+    #[test]
+    fn counts_offsets_in_chars() {
+        assert_eq!(
+            spans("Привіт, @alice! 東京 @bob"),
+            vec![(8, 14, "alice"), (19, 23, "bob")]
+        );
+    }
+
+    #[test]
+    fn accepts_mentions_after_punctuation() {
+        assert_eq!(spans("(@alice)"), vec![(1, 7, "alice")]);
+        assert_eq!(spans("ёж@alice"), vec![(2, 8, "alice")]);
+    }
+
+    #[test]
+    fn ignores_too_long_handles() {
+        let text = format!("@{}", "a".repeat(65));
+
+        assert_eq!(spans(&text), vec![]);
+    }
+}
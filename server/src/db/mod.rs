@@ -1,12 +1,18 @@
 pub mod comment;
 pub mod file;
+pub mod glossary;
 pub mod id;
 pub mod language;
+pub mod mention;
+pub mod notification;
 pub mod page;
 pub mod post;
+pub mod reaction;
 pub mod tag;
 pub mod translation;
+pub mod translation_memory;
 pub mod user;
+pub mod webhook;
 
 pub fn format_as_subquery(ids: &Vec<id::Id>) -> String {
     // TODO: more elegant solution
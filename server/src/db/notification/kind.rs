@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+pub const KIND_MENTION: &'static str = "Mention";
+pub const KIND_REPLY: &'static str = "Reply";
+pub const KIND_FOLLOW: &'static str = "Follow";
+pub const KIND_TRANSLATION_SUBMITTED: &'static str = "TranslationSubmitted";
+pub const KIND_TRANSLATION_APPROVED: &'static str = "TranslationApproved";
+pub const KIND_TRANSLATION_DENIED: &'static str = "TranslationDenied";
+pub const KIND_CHANGES_REQUESTED: &'static str = "ChangesRequested";
+pub const KIND_TRANSLATION_REQUEST_FULFILLED: &'static str = "TranslationRequestFulfilled";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, sqlx::Type)]
+#[serde(tag = "tag")]
+pub enum Kind {
+    Mention,
+    Reply,
+    Follow,
+    // A translation of the user's post is awaiting their review
+    TranslationSubmitted,
+    TranslationApproved,
+    TranslationDenied,
+    ChangesRequested,
+    TranslationRequestFulfilled,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Invalid,
+}
+
+impl Kind {
+    pub const ALL: [Self; 8] = [
+        Self::Mention,
+        Self::Reply,
+        Self::Follow,
+        Self::TranslationSubmitted,
+        Self::TranslationApproved,
+        Self::TranslationDenied,
+        Self::ChangesRequested,
+        Self::TranslationRequestFulfilled,
+    ];
+
+    pub fn from_str(kind: &str) -> Result<Self, Error> {
+        match kind {
+            KIND_MENTION => Ok(Self::Mention),
+            KIND_REPLY => Ok(Self::Reply),
+            KIND_FOLLOW => Ok(Self::Follow),
+            KIND_TRANSLATION_SUBMITTED => Ok(Self::TranslationSubmitted),
+            KIND_TRANSLATION_APPROVED => Ok(Self::TranslationApproved),
+            KIND_TRANSLATION_DENIED => Ok(Self::TranslationDenied),
+            KIND_CHANGES_REQUESTED => Ok(Self::ChangesRequested),
+            KIND_TRANSLATION_REQUEST_FULFILLED => Ok(Self::TranslationRequestFulfilled),
+            _ => Err(Error::Invalid),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mention => KIND_MENTION,
+            Self::Reply => KIND_REPLY,
+            Self::Follow => KIND_FOLLOW,
+            Self::TranslationSubmitted => KIND_TRANSLATION_SUBMITTED,
+            Self::TranslationApproved => KIND_TRANSLATION_APPROVED,
+            Self::TranslationDenied => KIND_TRANSLATION_DENIED,
+            Self::ChangesRequested => KIND_CHANGES_REQUESTED,
+            Self::TranslationRequestFulfilled => KIND_TRANSLATION_REQUEST_FULFILLED,
+        }
+    }
+}
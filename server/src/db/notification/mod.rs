@@ -0,0 +1,82 @@
+use crate::db::id::Id;
+use crate::realtime::Event;
+use sqlx::mysql::MySql;
+use sqlx::{Result, Transaction};
+
+pub mod kind;
+use kind::Kind;
+
+// `actor_id` is whoever caused the notification,
+// nobody gets notified about their own doings.
+// Kinds the user turned off are dropped right here.
+// The returned event is for publishing after commit
+pub async fn notify(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    kind: Kind,
+    actor_id: Id,
+    post_content_id: Option<Id>,
+    comment_id: Option<Id>,
+) -> Result<Option<Event>> {
+    if user_id == actor_id {
+        return Ok(None);
+    }
+
+    if !is_enabled(tx, user_id, kind).await? {
+        return Ok(None);
+    }
+
+    let notification_id = sqlx::query!(
+        "
+        INSERT INTO notifications
+        (
+            user_id,
+            kind,
+            actor_id,
+            post_content_id,
+            comment_id,
+            created_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        user_id,
+        kind.as_str(),
+        actor_id,
+        post_content_id,
+        comment_id
+    )
+    .execute(&mut *tx)
+    .await?
+    .last_insert_id() as Id;
+
+    Ok(Some(Event::Notification {
+        user_id,
+        notification_id,
+        kind,
+    }))
+}
+
+// Every kind is on until the user turns it off
+async fn is_enabled(tx: &mut Transaction<'_, MySql>, user_id: Id, kind: Kind) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            is_enabled
+        FROM notification_preferences
+        WHERE user_id = ? AND kind = ?
+        ",
+        user_id,
+        kind.as_str()
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.map_or(true, |row| row.is_enabled != 0))
+}
@@ -0,0 +1,87 @@
+use crate::db::id::Id;
+use crate::db::user::meta::Meta as UserMeta;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+// How long a translator can hold a (post, language) pair
+// before others are allowed to translate it again
+pub const CLAIM_DURATION_HOURS: i64 = 24;
+
+#[derive(Debug, Serialize)]
+pub struct Claim {
+    pub claimed_by: UserMeta,
+    pub expires_at: i64,
+}
+
+impl Claim {
+    pub async fn fetch_active(
+        db_pool: &MySqlPool,
+        post_id: Id,
+        language_id: Id,
+    ) -> Result<Option<Self>> {
+        #[derive(Debug)]
+        struct RawClaim {
+            claimed_by_id: Id,
+            claimed_by_handle: String,
+            claimed_by_profile_picture_file_name: Option<String>,
+            expires_at: NaiveDateTime,
+        }
+
+        sqlx::query_as!(
+            RawClaim,
+            "
+            SELECT
+                users.id AS claimed_by_id,
+                users.handle AS claimed_by_handle,
+                CONCAT(files.id, \".\", files.extension) AS `claimed_by_profile_picture_file_name?`,
+                translation_claims.expires_at
+            FROM translation_claims
+                JOIN users ON translation_claims.claimed_by = users.id
+                LEFT JOIN files ON users.profile_picture_file_id = files.id
+            WHERE
+                translation_claims.post_id = ?
+                AND translation_claims.language_id = ?
+                AND translation_claims.expires_at > NOW()
+            ",
+            post_id,
+            language_id
+        )
+        .fetch_optional(db_pool)
+        .await
+        .map(|result| {
+            result.map(|raw| Self {
+                claimed_by: UserMeta {
+                    id: raw.claimed_by_id,
+                    handle: raw.claimed_by_handle,
+                    profile_picture_file_name: raw.claimed_by_profile_picture_file_name,
+                },
+                expires_at: raw.expires_at.timestamp_millis(),
+            })
+        })
+    }
+}
+
+// Called once the claimed translation has been submitted
+pub async fn release_claim(
+    tx: &mut Transaction<'_, MySql>,
+    post_id: Id,
+    language_id: Id,
+    claimed_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_claims
+        WHERE
+            post_id = ?
+            AND language_id = ?
+            AND claimed_by = ?
+        ",
+        post_id,
+        language_id,
+        claimed_by
+    )
+    .execute(&mut *tx)
+    .await
+}
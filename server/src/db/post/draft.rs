@@ -0,0 +1,67 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+// A translation submitted within this window after
+// requesting a machine translated draft is flagged as such
+pub const DRAFT_WINDOW_HOURS: i64 = 24;
+
+pub async fn record_draft(
+    db_pool: &MySqlPool,
+    post_id: Id,
+    language_id: Id,
+    drafted_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        INSERT INTO translation_drafts
+        (
+            post_id,
+            language_id,
+            drafted_by,
+            drafted_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ON DUPLICATE KEY UPDATE
+            drafted_at = NOW()
+        ",
+        post_id,
+        language_id,
+        drafted_by
+    )
+    .execute(db_pool)
+    .await
+}
+
+pub async fn has_recent_draft(
+    db_pool: &MySqlPool,
+    post_id: Id,
+    language_id: Id,
+    drafted_by: Id,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM translation_drafts
+        WHERE
+            post_id = ?
+            AND language_id = ?
+            AND drafted_by = ?
+            AND drafted_at > NOW() - INTERVAL ? HOUR
+        ",
+        post_id,
+        language_id,
+        drafted_by,
+        DRAFT_WINDOW_HOURS
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
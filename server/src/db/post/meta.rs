@@ -1,4 +1,7 @@
 use crate::db::id::Id;
+use crate::db::language::expand_fallbacks;
+use crate::db::post::status::Status as PostStatus;
+use crate::db::reaction::{fetch_reactions, Reaction, Target as ReactionTarget};
 use crate::db::tag::fetch_tags_by_post_content_id;
 use crate::db::user::meta::Meta as UserMeta;
 use chrono::NaiveDateTime;
@@ -12,20 +15,33 @@ use std::collections::HashMap;
 pub struct Meta {
     pub id: Id,
     pub post_content_id: Id,
+    pub language_id: Id,
+    pub source_post_content_id: Option<Id>,
+    pub source_language_id: Option<Id>,
     pub title: String,
     pub description: String,
     pub tags: Vec<String>,
     pub posted_by: UserMeta,
     pub posted_at: i64,
+    pub reactions: Vec<Reaction>,
 }
 
 impl Meta {
-    async fn fetch_from_raw(db_pool: &MySqlPool, raw: RawMeta) -> Result<Self> {
+    async fn fetch_from_raw(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
+        raw: RawMeta,
+    ) -> Result<Self> {
         let tags = fetch_tags_by_post_content_id(db_pool, raw.post_content_id).await?;
+        let reactions =
+            fetch_reactions(db_pool, ReactionTarget::Post(raw.id), fetcher_user_id).await?;
 
         let meta = Self {
             id: raw.id,
             post_content_id: raw.post_content_id,
+            language_id: raw.language_id,
+            source_post_content_id: raw.source_post_content_id,
+            source_language_id: raw.source_language_id,
             title: raw.title,
             description: raw.description,
             tags,
@@ -35,6 +51,7 @@ impl Meta {
                 profile_picture_file_name: raw.posted_by_profile_picture_file_name,
             },
             posted_at: raw.posted_at.timestamp_millis(),
+            reactions,
         };
 
         Ok(meta)
@@ -42,9 +59,15 @@ impl Meta {
 
     pub async fn fetch_from_post_ids_with_best_language(
         db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
         fetcher_language_ids: &Vec<Id>,
+        prefers_source_language: bool,
         post_ids: &Vec<Id>,
     ) -> Result<Vec<Self>> {
+        // A translation in a fallback language beats
+        // the ones in languages the reader put after it
+        let fetcher_language_ids = expand_fallbacks(db_pool, fetcher_language_ids).await?;
+
         let language_max_priority = fetcher_language_ids.len();
 
         let mut language_priorities = HashMap::new();
@@ -58,12 +81,14 @@ impl Meta {
                 db_pool,
                 language_max_priority,
                 &language_priorities,
+                prefers_source_language,
                 *post_id,
             )
             .await?;
 
             if let Some(post_raw_meta) = post_raw_meta {
-                let post_meta = Meta::fetch_from_raw(db_pool, post_raw_meta).await?;
+                let post_meta =
+                    Meta::fetch_from_raw(db_pool, fetcher_user_id, post_raw_meta).await?;
 
                 post_metas.push(post_meta);
             }
@@ -78,6 +103,8 @@ pub struct RawMeta {
     pub id: Id,
     pub post_content_id: Id,
     pub language_id: Id,
+    pub source_post_content_id: Option<Id>,
+    pub source_language_id: Option<Id>,
     pub title: String,
     pub description: String,
 
@@ -89,10 +116,15 @@ pub struct RawMeta {
 }
 
 impl RawMeta {
+    fn is_source(&self) -> bool {
+        self.source_post_content_id == Some(self.post_content_id)
+    }
+
     async fn fetch_with_best_language(
         db_pool: &MySqlPool,
         language_max_priority: usize,
         language_priorities: &HashMap<Id, usize>,
+        prefers_source_language: bool,
         post_id: Id,
     ) -> Result<Option<Self>> {
         let mut cursor = sqlx::query_as!(
@@ -102,6 +134,13 @@ impl RawMeta {
                 posts.id,
                 post_contents.id AS post_content_id,
                 language_id,
+                posts.source_post_content_id,
+                (
+                    SELECT
+                        source_post_contents.language_id
+                    FROM post_contents AS source_post_contents
+                    WHERE source_post_contents.id = posts.source_post_content_id
+                ) AS `source_language_id?`,
                 title,
                 post_contents.description,
                 users.id as posted_by_id,
@@ -112,25 +151,51 @@ impl RawMeta {
                 JOIN post_contents ON posts.id = post_contents.post_id
                 JOIN users ON posts.posted_by = users.id
                 LEFT JOIN files ON users.profile_picture_file_id = files.id
-            WHERE posts.id = ?
+            WHERE
+                posts.id = ?
+                AND status = ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
+            ORDER BY
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM translation_votes
+                    WHERE translation_votes.post_content_id = post_contents.id
+                ) DESC,
+                post_contents.id
             ",
-            post_id
+            post_id,
+            PostStatus::Approved.as_str()
         )
         .fetch(db_pool);
 
+        // Variants come ordered by votes and only a strictly better
+        // language replaces the current best, so the top voted
+        // variant of a language wins over the other ones
         let mut best = match cursor.try_next().await? {
             Some(post_raw_meta) => post_raw_meta,
             None => return Ok(None),
         };
 
+        // Readers who always want the original get it
+        // regardless of their language priorities
+        if prefers_source_language && best.is_source() {
+            return Ok(Some(best));
+        }
+
         let mut best_language_priority = *language_priorities.get(&best.language_id).unwrap_or(&0);
 
         // Return early if the bestest possible language is already found
-        if best_language_priority == language_max_priority {
+        if !prefers_source_language && best_language_priority == language_max_priority {
             return Ok(Some(best));
         }
 
         while let Some(post_raw_meta) = cursor.try_next().await? {
+            if prefers_source_language && post_raw_meta.is_source() {
+                return Ok(Some(post_raw_meta));
+            }
+
             let language_priority = *language_priorities
                 .get(&post_raw_meta.language_id)
                 .unwrap_or(&0);
@@ -140,7 +205,7 @@ impl RawMeta {
                 best_language_priority = *language_priorities.get(&best.language_id).unwrap_or(&0);
 
                 // Return early if the bestest possible language is already found
-                if best_language_priority == language_max_priority {
+                if !prefers_source_language && best_language_priority == language_max_priority {
                     return Ok(Some(best));
                 }
             }
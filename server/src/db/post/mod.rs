@@ -1,10 +1,16 @@
 use crate::db::id::Id;
 use crate::db::post::tags::Tags;
-use sqlx::mysql::{MySqlPool, MySqlQueryResult};
-use sqlx::Result;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
 
+pub mod claim;
+pub mod draft;
 pub mod meta;
+pub mod review;
+pub mod revision;
+pub mod suggestion;
 pub mod tags;
+pub mod translation_request;
 
 pub mod title;
 use title::Title;
@@ -18,8 +24,15 @@ use content::Content;
 pub mod status;
 use status::Status;
 
+pub mod variant;
+use variant::Variant;
+
+// Soft deleted posts and translations can be restored
+// during this window, after that they are purged for good
+pub const RESTORE_WINDOW_DAYS: i64 = 30;
+
 pub async fn insert_post_content(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     post_id: Id,
     language_id: Id,
     title: &Title,
@@ -27,6 +40,8 @@ pub async fn insert_post_content(
     content: &Content,
     status: Status,
     translated_by: Id,
+    is_machine_translated: bool,
+    variant: Option<&Variant>,
 ) -> Result<MySqlQueryResult> {
     sqlx::query!(
         "
@@ -39,7 +54,9 @@ pub async fn insert_post_content(
                 content,
                 status,
                 translated_by,
-                translated_at
+                translated_at,
+                is_machine_translated,
+                variant
             )
             VALUES
             (
@@ -50,7 +67,9 @@ pub async fn insert_post_content(
                 ?,
                 ?,
                 ?,
-                NOW()
+                NOW(),
+                ?,
+                ?
             )
             ",
         post_id,
@@ -59,14 +78,16 @@ pub async fn insert_post_content(
         description.as_ref(),
         content.as_ref(),
         status.as_str(),
-        translated_by
+        translated_by,
+        is_machine_translated,
+        variant.map(|variant| variant.as_ref())
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
 
 pub async fn insert_post_content_tags(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     post_content_id: Id,
     tags: &Tags,
 ) -> Result<()> {
@@ -81,7 +102,7 @@ pub async fn insert_post_content_tags(
                 ",
             tag
         )
-        .fetch_optional(db_pool)
+        .fetch_optional(&mut *tx)
         .await?
         {
             Some(row) => row.id,
@@ -99,7 +120,7 @@ pub async fn insert_post_content_tags(
                         ",
                     tag
                 )
-                .execute(db_pool)
+                .execute(&mut *tx)
                 .await?;
 
                 tag_insert_result.last_insert_id() as Id
@@ -122,9 +143,53 @@ pub async fn insert_post_content_tags(
             post_content_id,
             tag_id
         )
-        .execute(db_pool)
+        .execute(&mut *tx)
         .await?;
     }
 
     Ok(())
 }
+
+// The content the post was originally written in
+pub async fn fetch_source_post_content_id(db_pool: &MySqlPool, post_id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            source_post_content_id
+        FROM posts
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        post_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.and_then(|row| row.source_post_content_id))
+}
+
+// Records the latest revision of the source content
+// as the one `post_content_id` is a translation of
+pub async fn update_source_revision(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    source_post_content_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE post_contents
+        SET
+            source_revision_id = (
+                SELECT
+                    MAX(id)
+                FROM post_content_revisions
+                WHERE post_content_id = ?
+            )
+        WHERE id = ?
+        ",
+        source_post_content_id,
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await
+}
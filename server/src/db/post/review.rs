@@ -0,0 +1,53 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status as PostStatus;
+use crate::text::paragraph;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+// A translation that is being reviewed,
+// along with the two people taking part in the review
+#[derive(Debug)]
+pub struct Target {
+    pub posted_by: Id,
+    pub translated_by: Id,
+    pub status: String,
+    pub content: String,
+}
+
+impl Target {
+    pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                posts.posted_by,
+                post_contents.translated_by,
+                post_contents.status,
+                post_contents.content
+            FROM posts
+                JOIN post_contents ON posts.id = post_contents.post_id
+            WHERE
+                post_contents.id = ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
+            ",
+            post_content_id
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+
+    pub fn is_participant(&self, user_id: Id) -> bool {
+        self.posted_by == user_id || self.translated_by == user_id
+    }
+
+    // Approved and denied translations are no longer open for review
+    pub fn is_open(&self) -> bool {
+        self.status == PostStatus::Pending.as_str()
+            || self.status == PostStatus::ChangesRequested.as_str()
+    }
+
+    pub fn paragraph(&self, index: u32) -> Option<&str> {
+        paragraph::get(&self.content, index as usize)
+    }
+}
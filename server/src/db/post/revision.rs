@@ -0,0 +1,231 @@
+use crate::db::id::Id;
+use crate::db::tag::fetch_tags_by_post_content_revision_id;
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::{Result, Transaction};
+
+#[derive(Debug)]
+pub struct Revision {
+    pub id: Id,
+    pub post_content_id: Id,
+    pub language_id: Id,
+    pub title: String,
+    pub description: String,
+    pub content: String,
+    pub tags: Vec<String>,
+}
+
+impl Revision {
+    pub async fn fetch_by_id(db_pool: &MySqlPool, id: Id) -> Result<Option<Self>> {
+        #[derive(Debug)]
+        struct RawRevision {
+            id: Id,
+            post_content_id: Id,
+            language_id: Id,
+            title: String,
+            description: String,
+            content: String,
+        }
+
+        let raw = match sqlx::query_as!(
+            RawRevision,
+            "
+            SELECT
+                id,
+                post_content_id,
+                language_id,
+                title,
+                description,
+                content
+            FROM post_content_revisions
+            WHERE id = ?
+            ",
+            id
+        )
+        .fetch_optional(db_pool)
+        .await?
+        {
+            Some(raw) => raw,
+            None => return Ok(None),
+        };
+
+        let tags = fetch_tags_by_post_content_revision_id(db_pool, raw.id).await?;
+
+        let revision = Self {
+            id: raw.id,
+            post_content_id: raw.post_content_id,
+            language_id: raw.language_id,
+            title: raw.title,
+            description: raw.description,
+            content: raw.content,
+            tags,
+        };
+
+        Ok(Some(revision))
+    }
+}
+
+// Snapshots the current state of a `post_contents` row
+pub async fn insert_revision(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    revised_by: Id,
+) -> Result<Id> {
+    let revision_id = sqlx::query!(
+        "
+        INSERT INTO post_content_revisions
+        (
+            post_content_id,
+            language_id,
+            title,
+            description,
+            content,
+            revised_by,
+            revised_at
+        )
+        SELECT
+            id,
+            language_id,
+            title,
+            description,
+            content,
+            ?,
+            NOW()
+        FROM post_contents
+        WHERE id = ?
+        ",
+        revised_by,
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?
+    .last_insert_id() as Id;
+
+    insert_revision_tags(tx, revision_id, post_content_id).await?;
+
+    Ok(revision_id)
+}
+
+// Rows created before revisions were tracked have no history,
+// so their current state becomes the first revision
+pub async fn insert_initial_revision(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+) -> Result<()> {
+    let has_revisions = sqlx::query!(
+        "
+        SELECT
+            id
+        FROM post_content_revisions
+        WHERE post_content_id = ?
+        LIMIT 1
+        ",
+        post_content_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .is_some();
+
+    if has_revisions {
+        return Ok(());
+    }
+
+    let revision_id = sqlx::query!(
+        "
+        INSERT INTO post_content_revisions
+        (
+            post_content_id,
+            language_id,
+            title,
+            description,
+            content,
+            revised_by,
+            revised_at
+        )
+        SELECT
+            id,
+            language_id,
+            title,
+            description,
+            content,
+            translated_by,
+            translated_at
+        FROM post_contents
+        WHERE id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?
+    .last_insert_id() as Id;
+
+    insert_revision_tags(tx, revision_id, post_content_id).await?;
+
+    Ok(())
+}
+
+async fn insert_revision_tags(
+    tx: &mut Transaction<'_, MySql>,
+    revision_id: Id,
+    post_content_id: Id,
+) -> Result<()> {
+    sqlx::query!(
+        "
+        INSERT INTO post_content_revision_tags
+        (
+            post_content_revision_id,
+            tag_id
+        )
+        SELECT
+            ?,
+            tag_id
+        FROM post_content_tags
+        WHERE post_content_id = ?
+        ",
+        revision_id,
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+// Revisions are visible to the author of the post
+// and to everyone who has worked on that content
+pub async fn can_access_revisions(
+    db_pool: &MySqlPool,
+    post_content_id: Id,
+    user_id: Id,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            post_contents.id
+        FROM posts
+            JOIN post_contents ON posts.id = post_contents.post_id
+        WHERE
+            post_contents.id = ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+            AND (
+                posted_by = ?
+                OR translated_by = ?
+                OR EXISTS (
+                    SELECT
+                        id
+                    FROM post_content_revisions
+                    WHERE
+                        post_content_id = post_contents.id
+                        AND revised_by = ?
+                )
+            )
+        ",
+        post_content_id,
+        user_id,
+        user_id,
+        user_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 pub const STATUS_PENDING: &'static str = "Pending";
 pub const STATUS_APPROVED: &'static str = "Approved";
 pub const STATUS_DENIED: &'static str = "Denied";
+pub const STATUS_CHANGES_REQUESTED: &'static str = "ChangesRequested";
 
 #[derive(Debug, Copy, Clone, Deserialize, Serialize, sqlx::Type)]
 #[serde(tag = "tag")]
@@ -10,6 +11,7 @@ pub enum Status {
     Pending,
     Approved,
     Denied,
+    ChangesRequested,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -23,6 +25,7 @@ impl Status {
             STATUS_PENDING => Ok(Self::Pending),
             STATUS_APPROVED => Ok(Self::Approved),
             STATUS_DENIED => Ok(Self::Denied),
+            STATUS_CHANGES_REQUESTED => Ok(Self::ChangesRequested),
             _ => Err(Error::Invalid),
         }
     }
@@ -32,6 +35,7 @@ impl Status {
             Self::Pending => STATUS_PENDING,
             Self::Approved => STATUS_APPROVED,
             Self::Denied => STATUS_DENIED,
+            Self::ChangesRequested => STATUS_CHANGES_REQUESTED,
         }
     }
 }
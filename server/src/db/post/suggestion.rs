@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+pub const STATUS_PENDING: &'static str = "Pending";
+pub const STATUS_ACCEPTED: &'static str = "Accepted";
+pub const STATUS_REJECTED: &'static str = "Rejected";
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, sqlx::Type)]
+#[serde(tag = "tag")]
+pub enum Status {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Invalid,
+}
+
+impl Status {
+    pub fn from_str(status: &str) -> Result<Self, Error> {
+        match status {
+            STATUS_PENDING => Ok(Self::Pending),
+            STATUS_ACCEPTED => Ok(Self::Accepted),
+            STATUS_REJECTED => Ok(Self::Rejected),
+            _ => Err(Error::Invalid),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => STATUS_PENDING,
+            Self::Accepted => STATUS_ACCEPTED,
+            Self::Rejected => STATUS_REJECTED,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use crate::db::id::Id;
+use crate::db::notification::{kind::Kind as NotificationKind, notify};
+use crate::realtime::Event;
+use sqlx::mysql::MySql;
+use sqlx::{Result, Transaction};
+
+// Marks every open request for the language of
+// `post_content_id` as fulfilled by that translation
+// and lets the requesters know
+pub async fn fulfill_translation_requests(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+) -> Result<Vec<Event>> {
+    let open_requests = sqlx::query!(
+        "
+        SELECT
+            translation_requests.requested_by,
+            post_contents.translated_by
+        FROM translation_requests
+            JOIN post_contents ON translation_requests.post_id = post_contents.post_id
+                AND translation_requests.language_id = post_contents.language_id
+        WHERE
+            post_contents.id = ?
+            AND translation_requests.fulfilled_at IS NULL
+        ",
+        post_content_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        UPDATE translation_requests
+            JOIN post_contents ON translation_requests.post_id = post_contents.post_id
+                AND translation_requests.language_id = post_contents.language_id
+        SET
+            translation_requests.post_content_id = post_contents.id,
+            translation_requests.fulfilled_at = NOW()
+        WHERE
+            post_contents.id = ?
+            AND translation_requests.fulfilled_at IS NULL
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let mut events = Vec::new();
+    for open_request in open_requests {
+        let event = notify(
+            tx,
+            open_request.requested_by,
+            NotificationKind::TranslationRequestFulfilled,
+            open_request.translated_by,
+            Some(post_content_id),
+            None,
+        )
+        .await?;
+
+        events.extend(event);
+    }
+
+    Ok(events)
+}
@@ -0,0 +1,46 @@
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Empty,
+    TooLong,
+}
+
+// Label telling approved translations into the same
+// language apart, e.g. "formal" and "casual"
+#[derive(Debug)]
+pub struct Variant(String);
+
+impl AsRef<str> for Variant {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Into<String> for Variant {
+    fn into(self) -> String {
+        self.0
+    }
+}
+
+impl Variant {
+    pub fn parse(variant: String) -> Result<Self, Error> {
+        Self::validate(&variant)?;
+
+        Ok(Self(variant))
+    }
+
+    pub fn max_char_count() -> usize {
+        32
+    }
+
+    fn validate(variant: &str) -> Result<(), Error> {
+        if variant.trim().is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if variant.chars().count() > Self::max_char_count() {
+            return Err(Error::TooLong);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use super::set::Set;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    NotInSet,
+}
+
+#[derive(Debug)]
+pub struct Emoji(String);
+
+impl AsRef<str> for Emoji {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Into<String> for Emoji {
+    fn into(self) -> String {
+        self.0
+    }
+}
+
+impl Emoji {
+    pub fn parse(emoji: String, set: &Set) -> Result<Self, Error> {
+        Self::validate(&emoji, set)?;
+
+        Ok(Self(emoji))
+    }
+
+    fn validate(emoji: &str, set: &Set) -> Result<(), Error> {
+        if !set.contains(emoji) {
+            return Err(Error::NotInSet);
+        }
+
+        Ok(())
+    }
+}
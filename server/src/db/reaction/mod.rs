@@ -0,0 +1,205 @@
+use super::id::Id;
+use serde::Serialize;
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::{Result, Transaction};
+
+pub mod emoji;
+use emoji::Emoji;
+
+pub mod set;
+
+// Reactions are on the post as a whole, shared by all of its translations
+#[derive(Debug, Copy, Clone)]
+pub enum Target {
+    Post(Id),
+    Comment(Id),
+}
+
+#[derive(Debug, Serialize)]
+pub struct Reaction {
+    pub emoji: String,
+    pub count: i64,
+    // Whether the fetcher is one of the ones who reacted with it
+    pub is_mine: bool,
+}
+
+#[derive(Debug)]
+struct RawReaction {
+    emoji: String,
+    count: i64,
+    is_mine: i64,
+}
+
+impl From<RawReaction> for Reaction {
+    fn from(raw: RawReaction) -> Self {
+        Self {
+            emoji: raw.emoji,
+            count: raw.count,
+            is_mine: raw.is_mine != 0,
+        }
+    }
+}
+
+// In the order the emojis were first reacted with,
+// so that the list doesn't jump around as counts change
+pub async fn fetch_reactions(
+    db_pool: &MySqlPool,
+    target: Target,
+    fetcher_user_id: Option<Id>,
+) -> Result<Vec<Reaction>> {
+    let fetcher_user_id = fetcher_user_id.unwrap_or(0);
+
+    let raw_reactions = match target {
+        Target::Post(post_id) => {
+            sqlx::query_as!(
+                RawReaction,
+                "
+                SELECT
+                    emoji,
+                    COUNT(*) AS `count!`,
+                    MAX(user_id = ?) AS `is_mine!: i64`
+                FROM reactions
+                WHERE post_id = ?
+                GROUP BY emoji
+                ORDER BY MIN(id)
+                ",
+                fetcher_user_id,
+                post_id
+            )
+            .fetch_all(db_pool)
+            .await?
+        }
+        Target::Comment(comment_id) => {
+            sqlx::query_as!(
+                RawReaction,
+                "
+                SELECT
+                    emoji,
+                    COUNT(*) AS `count!`,
+                    MAX(user_id = ?) AS `is_mine!: i64`
+                FROM reactions
+                WHERE comment_id = ?
+                GROUP BY emoji
+                ORDER BY MIN(id)
+                ",
+                fetcher_user_id,
+                comment_id
+            )
+            .fetch_all(db_pool)
+            .await?
+        }
+    };
+
+    Ok(raw_reactions.into_iter().map(|raw| raw.into()).collect())
+}
+
+// Toggles, returns whether the user has reacted with `emoji` afterwards
+pub async fn update_reaction(
+    tx: &mut Transaction<'_, MySql>,
+    target: Target,
+    user_id: Id,
+    emoji: &Emoji,
+) -> Result<bool> {
+    if let Some(reaction_id) = fetch_reaction_id(tx, target, user_id, emoji).await? {
+        delete_reaction(tx, reaction_id).await?;
+
+        Ok(false)
+    } else {
+        create_reaction(tx, target, user_id, emoji).await?;
+
+        Ok(true)
+    }
+}
+
+async fn create_reaction(
+    tx: &mut Transaction<'_, MySql>,
+    target: Target,
+    user_id: Id,
+    emoji: &Emoji,
+) -> Result<()> {
+    let (post_id, comment_id) = match target {
+        Target::Post(post_id) => (Some(post_id), None),
+        Target::Comment(comment_id) => (None, Some(comment_id)),
+    };
+
+    sqlx::query!(
+        "
+        INSERT INTO reactions
+        (
+            user_id,
+            post_id,
+            comment_id,
+            emoji,
+            created_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        user_id,
+        post_id,
+        comment_id,
+        emoji.as_ref()
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+async fn delete_reaction(tx: &mut Transaction<'_, MySql>, reaction_id: Id) -> Result<()> {
+    sqlx::query!(
+        "
+        DELETE FROM reactions
+        WHERE id = ?
+        ",
+        reaction_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+async fn fetch_reaction_id(
+    tx: &mut Transaction<'_, MySql>,
+    target: Target,
+    user_id: Id,
+    emoji: &Emoji,
+) -> Result<Option<Id>> {
+    match target {
+        Target::Post(post_id) => sqlx::query!(
+            "
+            SELECT
+                id
+            FROM reactions
+            WHERE post_id = ? AND user_id = ? AND emoji = ?
+            ",
+            post_id,
+            user_id,
+            emoji.as_ref()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map(|result| result.map(|row| row.id)),
+        Target::Comment(comment_id) => sqlx::query!(
+            "
+            SELECT
+                id
+            FROM reactions
+            WHERE comment_id = ? AND user_id = ? AND emoji = ?
+            ",
+            comment_id,
+            user_id,
+            emoji.as_ref()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map(|result| result.map(|row| row.id)),
+    }
+}
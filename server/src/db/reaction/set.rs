@@ -0,0 +1,39 @@
+// Used when `$REACTIONS` is not set
+pub const DEFAULT: &'static str = "👍,❤️,😂,🎉,😮,😢";
+
+// Matches `reactions.emoji`
+pub const MAX_EMOJI_CHAR_COUNT: usize = 32;
+
+// The emojis readers can react with, in the order they're offered
+#[derive(Debug, Clone)]
+pub struct Set(Vec<String>);
+
+impl AsRef<[String]> for Set {
+    fn as_ref(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl Set {
+    // Comma separated, e.g. `REACTIONS=👍,❤️,🎉`
+    pub fn from_env() -> Self {
+        let emojis = std::env::var("REACTIONS").unwrap_or_else(|_| DEFAULT.to_owned());
+
+        let mut set = Vec::new();
+        for emoji in emojis.split(',').map(str::trim) {
+            if emoji.is_empty() || emoji.chars().count() > MAX_EMOJI_CHAR_COUNT {
+                panic!("Invalid emoji `{emoji}` in $REACTIONS");
+            }
+
+            if !set.iter().any(|existing| existing == emoji) {
+                set.push(emoji.to_owned());
+            }
+        }
+
+        Self(set)
+    }
+
+    pub fn contains(&self, emoji: &str) -> bool {
+        self.0.iter().any(|existing| existing == emoji)
+    }
+}
@@ -28,6 +28,26 @@ pub async fn fetch_tags_by_post_content_id(
     .map(|tags| tags.into_iter().map(|tag| tag.name).collect())
 }
 
+pub async fn fetch_tags_by_post_content_revision_id(
+    db_pool: &MySqlPool,
+    post_content_revision_id: Id,
+) -> Result<Vec<String>> {
+    sqlx::query_as!(
+        Tag,
+        "
+        SELECT
+            name
+        FROM post_content_revision_tags
+            JOIN tags ON post_content_revision_tags.tag_id = tags.id
+        WHERE post_content_revision_id = ?
+        ",
+        post_content_revision_id
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|tags| tags.into_iter().map(|tag| tag.name).collect())
+}
+
 pub async fn fetch_user_tag_ids(db_pool: &MySqlPool, user_id: Id) -> Result<Vec<Id>> {
     sqlx::query_as!(
         IdRow,
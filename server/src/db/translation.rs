@@ -3,29 +3,102 @@ use super::post::status::Status as PostStatus;
 use serde::Serialize;
 use sqlx::mysql::MySqlPool;
 use sqlx::Result;
+use std::collections::HashSet;
 
 #[derive(Debug, Serialize)]
 pub struct Translation {
     pub post_content_id: Id,
     pub language_id: Id,
     pub language: String,
+    pub is_outdated: bool,
+    pub variant: Option<String>,
+    pub vote_count: i64,
+    // The top voted variant is the one readers get by default,
+    // the other ones are listed as alternatives
+    pub is_default: bool,
 }
 
 impl Translation {
     pub async fn fetch_by_post_id(db_pool: &MySqlPool, post_id: Id) -> Result<Vec<Translation>> {
+        let raw_translations = RawTranslation::fetch_by_post_id(db_pool, post_id).await?;
+
+        // Variants come ordered by votes, so the first one
+        // seen in each language is the default one
+        let mut seen_language_ids = HashSet::new();
+        let translations = raw_translations
+            .into_iter()
+            .map(|raw_translation| {
+                let is_default = seen_language_ids.insert(raw_translation.language_id);
+
+                Translation::from_raw(raw_translation, is_default)
+            })
+            .collect();
+
+        Ok(translations)
+    }
+}
+
+impl Translation {
+    fn from_raw(raw: RawTranslation, is_default: bool) -> Self {
+        Self {
+            post_content_id: raw.post_content_id,
+            language_id: raw.language_id,
+            language: raw.language,
+            is_outdated: raw.newer_source_revision_count != 0,
+            variant: raw.variant,
+            vote_count: raw.vote_count,
+            is_default,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawTranslation {
+    pub post_content_id: Id,
+    pub language_id: Id,
+    pub language: String,
+    pub newer_source_revision_count: i64,
+    pub variant: Option<String>,
+    pub vote_count: i64,
+}
+
+impl RawTranslation {
+    async fn fetch_by_post_id(db_pool: &MySqlPool, post_id: Id) -> Result<Vec<Self>> {
+        // A translation is outdated once the source content
+        // it was based on has been revised since
         sqlx::query_as!(
-            Translation,
+            Self,
             "
             SELECT
                 post_contents.id AS post_content_id,
                 languages.id AS language_id,
-                name AS language
+                name AS language,
+                COALESCE(newer_source_revision_counts.newer_source_revision_count, 0) AS `newer_source_revision_count!`,
+                variant,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM translation_votes
+                    WHERE translation_votes.post_content_id = post_contents.id
+                ) AS `vote_count!`
             FROM posts
                 JOIN post_contents ON posts.id = post_contents.post_id
+                LEFT JOIN newer_source_revision_counts ON post_contents.id = newer_source_revision_counts.post_content_id
                 JOIN languages ON post_contents.language_id = languages.id
             WHERE
                 posts.id = ?
                 AND status = ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
+            ORDER BY
+                language_id,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM translation_votes
+                    WHERE translation_votes.post_content_id = post_contents.id
+                ) DESC,
+                post_contents.id
             ",
             post_id,
             PostStatus::Approved.as_str(),
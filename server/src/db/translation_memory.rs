@@ -0,0 +1,126 @@
+use super::id::{Id, IdRow};
+use crate::text::segment;
+use sqlx::mysql::MySql;
+use sqlx::{QueryBuilder, Result, Transaction};
+
+pub const SEGMENT_MAX_CHAR_COUNT: usize = 2_048;
+
+// Splits an approved translation and the content it was translated from
+// into aligned segments, replacing whatever was stored for it before
+pub async fn update_translation_memory(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+) -> Result<()> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_memory
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let pair = match sqlx::query!(
+        "
+        SELECT
+            source_post_contents.id AS source_post_content_id,
+            source_post_contents.language_id AS source_language_id,
+            source_post_contents.content AS source_content,
+            translated_post_contents.language_id AS target_language_id,
+            translated_post_contents.content AS target_content
+        FROM post_contents AS translated_post_contents
+            JOIN posts ON translated_post_contents.post_id = posts.id
+            JOIN post_contents AS source_post_contents ON source_post_contents.id = posts.source_post_content_id
+        WHERE translated_post_contents.id = ?
+        ",
+        post_content_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    {
+        Some(pair) => pair,
+        None => return Ok(()),
+    };
+
+    if pair.source_post_content_id == post_content_id
+        || pair.source_language_id == pair.target_language_id
+    {
+        return Ok(());
+    }
+
+    let segments: Vec<(&str, &str)> = segment::pairs(&pair.source_content, &pair.target_content)
+        .into_iter()
+        .filter(|(source_segment, target_segment)| {
+            source_segment.chars().count() <= SEGMENT_MAX_CHAR_COUNT
+                && target_segment.chars().count() <= SEGMENT_MAX_CHAR_COUNT
+        })
+        .collect();
+
+    if segments.is_empty() {
+        return Ok(());
+    }
+
+    let mut query_builder = QueryBuilder::<MySql>::new(
+        "
+        INSERT INTO translation_memory
+        (
+            post_content_id,
+            source_language_id,
+            target_language_id,
+            source_segment,
+            target_segment,
+            created_at
+        )
+        ",
+    );
+
+    query_builder.push_values(segments, |mut row, (source_segment, target_segment)| {
+        row.push_bind(post_content_id)
+            .push_bind(pair.source_language_id)
+            .push_bind(pair.target_language_id)
+            .push_bind(source_segment)
+            .push_bind(target_segment)
+            .push("NOW()");
+    });
+
+    query_builder.build().execute(&mut *tx).await?;
+
+    Ok(())
+}
+
+// Called whenever content changes outside of approval (edits, rollbacks, suggestions).
+// Editing the source misaligns every translation of the post, so they are all rebuilt
+pub async fn refresh_translation_memory(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+) -> Result<()> {
+    let post_content_ids: Vec<Id> = sqlx::query_as!(
+        IdRow,
+        "
+        SELECT
+            translated_post_contents.id
+        FROM post_contents AS edited_post_contents
+            JOIN posts ON edited_post_contents.post_id = posts.id
+            JOIN post_contents AS translated_post_contents ON translated_post_contents.post_id = posts.id
+        WHERE
+            edited_post_contents.id = ?
+            AND (
+                translated_post_contents.id = edited_post_contents.id
+                OR posts.source_post_content_id = edited_post_contents.id
+            )
+        ",
+        post_content_id
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|id_row| id_row.id)
+    .collect();
+
+    for post_content_id in post_content_ids {
+        update_translation_memory(tx, post_content_id).await?;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use regex::Regex;
 
+// Characters a handle is made of, as a regex character class body
+pub const HANDLE_CHARS: &'static str = "\\-_A-Za-z0-9";
+
 #[derive(Debug, Copy, Clone)]
 pub enum Error {
     Empty,
@@ -43,7 +46,8 @@ impl Handle {
         }
 
         // TODO: lazy_static!
-        let regex = Regex::new("^[\\-_A-Za-z0-9]+$").expect("Failed to compile handle regex");
+        let regex =
+            Regex::new(&format!("^[{HANDLE_CHARS}]+$")).expect("Failed to compile handle regex");
 
         if !regex.is_match(s) {
             return Err(Error::BadChar);
@@ -5,6 +5,7 @@ use uuid::Uuid;
 
 pub mod handle;
 pub mod meta;
+pub mod recipient;
 pub mod role;
 
 pub async fn update_retoken(db_pool: &MySqlPool, id: Id) -> Result<(String, MySqlQueryResult)> {
@@ -24,3 +25,24 @@ pub async fn update_retoken(db_pool: &MySqlPool, id: Id) -> Result<(String, MySq
 
     Ok((retoken, result))
 }
+
+pub async fn fetch_prefers_source_language(db_pool: &MySqlPool, id: Id) -> Result<bool> {
+    #[derive(Debug)]
+    struct User {
+        prefers_source_language: i8,
+    }
+
+    sqlx::query_as!(
+        User,
+        "
+        SELECT
+            prefers_source_language
+        FROM users
+        WHERE id = ?
+        ",
+        id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map_or(false, |user| user.prefers_source_language != 0))
+}
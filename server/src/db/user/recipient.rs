@@ -0,0 +1,74 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+use uuid::Uuid;
+
+// Where and in which language to email a user
+#[derive(Debug)]
+pub struct Recipient {
+    pub email: String,
+    // Of the first language the user prefers
+    pub language_code: Option<String>,
+    pub unsubscribe_token: String,
+}
+
+impl Recipient {
+    // Users get their unsubscribe token with the first email
+    pub async fn fetch(db_pool: &MySqlPool, user_id: Id) -> Result<Option<Self>> {
+        let raw = match sqlx::query!(
+            "
+            SELECT
+                users.email,
+                users.unsubscribe_token,
+                (
+                    SELECT
+                        languages.code
+                    FROM user_languages
+                        JOIN languages ON user_languages.language_id = languages.id
+                    WHERE user_languages.user_id = users.id
+                    ORDER BY user_languages.id
+                    LIMIT 1
+                ) AS `language_code?`
+            FROM users
+            WHERE id = ?
+            ",
+            user_id
+        )
+        .fetch_optional(db_pool)
+        .await?
+        {
+            Some(raw) => raw,
+            None => return Ok(None),
+        };
+
+        let unsubscribe_token = match raw.unsubscribe_token {
+            Some(unsubscribe_token) => unsubscribe_token,
+            None => create_unsubscribe_token(db_pool, user_id).await?,
+        };
+
+        Ok(Some(Self {
+            email: raw.email,
+            language_code: raw.language_code,
+            unsubscribe_token,
+        }))
+    }
+}
+
+async fn create_unsubscribe_token(db_pool: &MySqlPool, user_id: Id) -> Result<String> {
+    let unsubscribe_token = Uuid::new_v4().to_string();
+
+    sqlx::query!(
+        "
+        UPDATE users
+        SET
+            unsubscribe_token = ?
+        WHERE id = ?
+        ",
+        unsubscribe_token,
+        user_id
+    )
+    .execute(db_pool)
+    .await?;
+
+    Ok(unsubscribe_token)
+}
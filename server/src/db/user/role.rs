@@ -71,6 +71,20 @@ impl Role {
         }
     }
 
+    pub fn can_delete_posts(&self) -> bool {
+        match self {
+            Self::Admin | Self::Mod | Self::Member => true,
+            _ => false,
+        }
+    }
+
+    pub fn can_moderate(&self) -> bool {
+        match self {
+            Self::Admin | Self::Mod => true,
+            _ => false,
+        }
+    }
+
     pub fn can_comment(&self) -> bool {
         match self {
             Self::Admin | Self::Mod | Self::Member => true,
@@ -91,4 +105,54 @@ impl Role {
             _ => false,
         }
     }
+
+    pub fn can_manage_glossaries(&self) -> bool {
+        match self {
+            Self::Admin | Self::Mod | Self::Member => true,
+            _ => false,
+        }
+    }
+
+    pub fn can_request_translations(&self) -> bool {
+        match self {
+            Self::Admin | Self::Mod | Self::Member => true,
+            _ => false,
+        }
+    }
+
+    pub fn can_vote(&self) -> bool {
+        match self {
+            Self::Admin | Self::Mod | Self::Member => true,
+            _ => false,
+        }
+    }
+
+    pub fn can_react(&self) -> bool {
+        match self {
+            Self::Admin | Self::Mod | Self::Member => true,
+            _ => false,
+        }
+    }
+
+    pub fn can_manage_languages(&self) -> bool {
+        match self {
+            Self::Admin => true,
+            _ => false,
+        }
+    }
+
+    pub fn can_register_webhooks(&self) -> bool {
+        match self {
+            Self::Admin | Self::Mod | Self::Member => true,
+            _ => false,
+        }
+    }
+
+    // Global webhooks see events on everybody's posts
+    pub fn can_register_global_webhooks(&self) -> bool {
+        match self {
+            Self::Admin => true,
+            _ => false,
+        }
+    }
 }
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+pub const EVENT_POST_CREATED: &'static str = "PostCreated";
+pub const EVENT_POST_EDITED: &'static str = "PostEdited";
+pub const EVENT_POST_DELETED: &'static str = "PostDeleted";
+pub const EVENT_TRANSLATION_SUBMITTED: &'static str = "TranslationSubmitted";
+pub const EVENT_TRANSLATION_APPROVED: &'static str = "TranslationApproved";
+pub const EVENT_TRANSLATION_DENIED: &'static str = "TranslationDenied";
+pub const EVENT_COMMENT_CREATED: &'static str = "CommentCreated";
+pub const EVENT_PING: &'static str = "Ping";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize, sqlx::Type)]
+#[serde(tag = "tag")]
+pub enum Event {
+    PostCreated,
+    PostEdited,
+    PostDeleted,
+    TranslationSubmitted,
+    TranslationApproved,
+    TranslationDenied,
+    CommentCreated,
+    // Sent by the test endpoint only, can't be subscribed to
+    Ping,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Invalid,
+}
+
+impl Event {
+    pub fn from_str(event: &str) -> Result<Self, Error> {
+        match event {
+            EVENT_POST_CREATED => Ok(Self::PostCreated),
+            EVENT_POST_EDITED => Ok(Self::PostEdited),
+            EVENT_POST_DELETED => Ok(Self::PostDeleted),
+            EVENT_TRANSLATION_SUBMITTED => Ok(Self::TranslationSubmitted),
+            EVENT_TRANSLATION_APPROVED => Ok(Self::TranslationApproved),
+            EVENT_TRANSLATION_DENIED => Ok(Self::TranslationDenied),
+            EVENT_COMMENT_CREATED => Ok(Self::CommentCreated),
+            EVENT_PING => Ok(Self::Ping),
+            _ => Err(Error::Invalid),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PostCreated => EVENT_POST_CREATED,
+            Self::PostEdited => EVENT_POST_EDITED,
+            Self::PostDeleted => EVENT_POST_DELETED,
+            Self::TranslationSubmitted => EVENT_TRANSLATION_SUBMITTED,
+            Self::TranslationApproved => EVENT_TRANSLATION_APPROVED,
+            Self::TranslationDenied => EVENT_TRANSLATION_DENIED,
+            Self::CommentCreated => EVENT_COMMENT_CREATED,
+            Self::Ping => EVENT_PING,
+        }
+    }
+
+    // Stored as a MySQL `SET`, which reads back comma separated
+    pub fn parse_set(events: &str) -> Vec<Self> {
+        events
+            .split(',')
+            .filter_map(|event| Self::from_str(event).ok())
+            .collect()
+    }
+
+    pub fn format_set(events: &[Self]) -> String {
+        events
+            .iter()
+            .map(|event| event.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
@@ -0,0 +1,223 @@
+use crate::db::id::Id;
+use chrono::Utc;
+use serde::Serialize;
+use sqlx::mysql::MySql;
+use sqlx::{Result, Transaction};
+
+pub mod event;
+use event::Event;
+
+pub mod url;
+
+// What an event happened to
+#[derive(Debug, Copy, Clone)]
+pub enum Subject {
+    Post(Id),
+    PostContent(Id),
+    Comment(Id),
+}
+
+// What gets POSTed, signed by the delivery job.
+// Content is a snapshot from the moment the event happened,
+// `content` is the comment's for `CommentCreated`
+#[derive(Debug, Serialize)]
+pub struct Payload {
+    pub event: Event,
+    pub occurred_at: i64,
+    pub post_id: Option<Id>,
+    pub post_content_id: Option<Id>,
+    pub comment_id: Option<Id>,
+    pub language_code: Option<String>,
+    pub title: Option<String>,
+    pub content: Option<String>,
+}
+
+#[derive(Debug)]
+struct Snapshot {
+    post_id: Id,
+    posted_by: Id,
+    post_content_id: Option<Id>,
+    language_code: Option<String>,
+    title: Option<String>,
+    content: Option<String>,
+}
+
+// Queues a delivery for every webhook subscribed to `event`.
+// Webhooks see events on their owner's posts, global ones see all.
+// Being part of the transaction, nothing is sent for rolled back changes
+pub async fn enqueue(
+    tx: &mut Transaction<'_, MySql>,
+    event: Event,
+    subject: Subject,
+) -> Result<()> {
+    let snapshot = match fetch_snapshot(tx, subject).await? {
+        Some(snapshot) => snapshot,
+        None => return Ok(()),
+    };
+
+    let payload = Payload {
+        event,
+        occurred_at: Utc::now().timestamp_millis(),
+        post_id: Some(snapshot.post_id),
+        post_content_id: snapshot.post_content_id,
+        comment_id: match subject {
+            Subject::Comment(comment_id) => Some(comment_id),
+            _ => None,
+        },
+        language_code: snapshot.language_code,
+        title: snapshot.title,
+        content: snapshot.content,
+    };
+
+    let payload = serde_json::to_string(&payload)
+        .expect("`Payload` only holds ids, strings and enums, it always serializes");
+
+    sqlx::query!(
+        "
+        INSERT INTO webhook_deliveries
+        (
+            webhook_id,
+            event,
+            payload,
+            attempt_count,
+            next_attempt_at,
+            created_at
+        )
+        SELECT
+            id,
+            ?,
+            ?,
+            0,
+            NOW(),
+            NOW()
+        FROM webhooks
+        WHERE
+            FIND_IN_SET(?, events) > 0
+            AND (is_global OR user_id = ?)
+        ",
+        event.as_str(),
+        payload,
+        event.as_str(),
+        snapshot.posted_by
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+// Queues a `Ping` for one webhook, regardless of what it's subscribed to
+pub async fn enqueue_ping(tx: &mut Transaction<'_, MySql>, webhook_id: Id) -> Result<Id> {
+    let payload = Payload {
+        event: Event::Ping,
+        occurred_at: Utc::now().timestamp_millis(),
+        post_id: None,
+        post_content_id: None,
+        comment_id: None,
+        language_code: None,
+        title: None,
+        content: None,
+    };
+
+    let payload = serde_json::to_string(&payload)
+        .expect("`Payload` only holds ids, strings and enums, it always serializes");
+
+    sqlx::query!(
+        "
+        INSERT INTO webhook_deliveries
+        (
+            webhook_id,
+            event,
+            payload,
+            attempt_count,
+            next_attempt_at,
+            created_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            0,
+            NOW(),
+            NOW()
+        )
+        ",
+        webhook_id,
+        Event::Ping.as_str(),
+        payload
+    )
+    .execute(&mut *tx)
+    .await
+    .map(|result| result.last_insert_id() as Id)
+}
+
+async fn fetch_snapshot(
+    tx: &mut Transaction<'_, MySql>,
+    subject: Subject,
+) -> Result<Option<Snapshot>> {
+    match subject {
+        Subject::Post(post_id) => {
+            sqlx::query_as!(
+                Snapshot,
+                "
+                SELECT
+                    id AS post_id,
+                    posted_by,
+                    NULL AS `post_content_id?: Id`,
+                    NULL AS `language_code?: String`,
+                    NULL AS `title?: String`,
+                    NULL AS `content?: String`
+                FROM posts
+                WHERE id = ?
+                ",
+                post_id
+            )
+            .fetch_optional(&mut *tx)
+            .await
+        }
+        Subject::PostContent(post_content_id) => {
+            sqlx::query_as!(
+                Snapshot,
+                "
+                SELECT
+                    posts.id AS post_id,
+                    posts.posted_by,
+                    post_contents.id AS `post_content_id?`,
+                    languages.code AS `language_code?`,
+                    post_contents.title AS `title?`,
+                    post_contents.content AS `content?`
+                FROM post_contents
+                    JOIN posts ON post_contents.post_id = posts.id
+                    JOIN languages ON post_contents.language_id = languages.id
+                WHERE post_contents.id = ?
+                ",
+                post_content_id
+            )
+            .fetch_optional(&mut *tx)
+            .await
+        }
+        Subject::Comment(comment_id) => {
+            sqlx::query_as!(
+                Snapshot,
+                "
+                SELECT
+                    posts.id AS post_id,
+                    posts.posted_by,
+                    post_contents.id AS `post_content_id?`,
+                    languages.code AS `language_code?`,
+                    NULL AS `title?: String`,
+                    comments.content AS `content?`
+                FROM comments
+                    JOIN post_contents ON comments.post_content_id = post_contents.id
+                    JOIN posts ON post_contents.post_id = posts.id
+                    JOIN languages ON post_contents.language_id = languages.id
+                WHERE comments.id = ?
+                ",
+                comment_id
+            )
+            .fetch_optional(&mut *tx)
+            .await
+        }
+    }
+}
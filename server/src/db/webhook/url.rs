@@ -0,0 +1,216 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+#[derive(Debug, Copy, Clone)]
+pub enum Error {
+    Empty,
+    TooLong,
+    BadScheme,
+    Invalid,
+    Unresolvable,
+    // Loopback, private, link-local and the like,
+    // webhooks must not be a way into the server's own network
+    PrivateAddress,
+}
+
+#[derive(Debug)]
+pub struct Url(String);
+
+impl AsRef<str> for Url {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Into<String> for Url {
+    fn into(self) -> String {
+        self.0
+    }
+}
+
+impl Url {
+    pub fn parse(url: String) -> Result<Self, Error> {
+        Self::validate(&url)?;
+
+        Ok(Self(url))
+    }
+
+    pub fn max_char_count() -> usize {
+        2048
+    }
+
+    fn validate(url: &str) -> Result<(), Error> {
+        if url.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if url.chars().count() > Self::max_char_count() {
+            return Err(Error::TooLong);
+        }
+
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(Error::BadScheme);
+        }
+
+        if reqwest::Url::parse(url).is_err() {
+            return Err(Error::Invalid);
+        }
+
+        Ok(())
+    }
+}
+
+// Where a webhook is actually delivered to. `domain` is `None` for IP literals
+#[derive(Debug)]
+pub struct Target {
+    pub domain: Option<String>,
+    pub address: SocketAddr,
+}
+
+// Checked both on registration and before every delivery,
+// since what a domain resolves to can change in between.
+// Every address has to be public, not just the first one
+pub async fn resolve_public(url: &str) -> Result<Target, Error> {
+    let url = reqwest::Url::parse(url).map_err(|_| Error::Invalid)?;
+    let host = url.host_str().ok_or(Error::Invalid)?;
+    let port = url.port_or_known_default().ok_or(Error::Invalid)?;
+
+    let ip_literal = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>();
+
+    let (domain, addresses): (Option<String>, Vec<SocketAddr>) = match ip_literal {
+        Ok(ip) => (None, vec![SocketAddr::new(ip, port)]),
+        Err(_) => {
+            let addresses = tokio::net::lookup_host((host, port))
+                .await
+                .map_err(|_| Error::Unresolvable)?
+                .collect();
+
+            (Some(host.to_owned()), addresses)
+        }
+    };
+
+    let address = match addresses.first() {
+        Some(address) => *address,
+        None => return Err(Error::Unresolvable),
+    };
+
+    if !addresses.iter().all(|address| is_public(address.ip())) {
+        return Err(Error::PrivateAddress);
+    }
+
+    Ok(Target { domain, address })
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        // 0.0.0.0/8 "this network"
+        || a == 0
+        // 100.64.0.0/10 carrier-grade NAT
+        || (a == 100 && (64..128).contains(&b))
+        // 198.18.0.0/15 benchmarking
+        || (a == 198 && (18..20).contains(&b))
+        // 240.0.0.0/4 reserved
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // fc00::/7 unique local
+        || (first & 0xfe00) == 0xfc00
+        // fe80::/10 link-local
+        || (first & 0xffc0) == 0xfe80
+        // 2001:db8::/32 documentation
+        || (first == 0x2001 && ip.segments()[1] == 0x0db8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_public_str(ip: &str) -> bool {
+        is_public(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn accepts_public_addresses() {
+        assert!(is_public_str("93.184.216.34"));
+        assert!(is_public_str("1.1.1.1"));
+        assert!(is_public_str("2606:4700:4700::1111"));
+    }
+
+    #[test]
+    fn rejects_internal_v4_addresses() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "0.0.0.0",
+            "0.1.2.3",
+            "100.64.0.1",
+            "255.255.255.255",
+            "224.0.0.1",
+        ] {
+            assert!(!is_public_str(ip), "{ip}");
+        }
+    }
+
+    #[test]
+    fn rejects_internal_v6_addresses() {
+        for ip in ["::1", "::", "fd00::1", "fe80::1", "ff02::1", "2001:db8::1"] {
+            assert!(!is_public_str(ip), "{ip}");
+        }
+    }
+
+    #[test]
+    fn checks_mapped_v4_addresses() {
+        assert!(!is_public_str("::ffff:127.0.0.1"));
+        assert!(!is_public_str("::ffff:10.0.0.1"));
+        assert!(is_public_str("::ffff:1.1.1.1"));
+    }
+
+    #[tokio::test]
+    async fn rejects_private_ip_literals() {
+        assert!(matches!(
+            resolve_public("http://127.0.0.1:8080/hook").await,
+            Err(Error::PrivateAddress)
+        ));
+        assert!(matches!(
+            resolve_public("http://[::1]/hook").await,
+            Err(Error::PrivateAddress)
+        ));
+    }
+
+    #[tokio::test]
+    async fn keeps_public_ip_literals() {
+        let target = resolve_public("https://1.1.1.1/hook").await.unwrap();
+
+        assert_eq!(target.domain, None);
+        assert_eq!(target.address, "1.1.1.1:443".parse().unwrap());
+    }
+}
@@ -1,10 +1,18 @@
 use crate::db::comment::content::Error as CommentContentError;
+use crate::db::glossary::term::Error as GlossaryTermError;
+use crate::db::language::{
+    code::Error as LanguageCodeError, name::Error as LanguageNameError,
+    script::Error as LanguageScriptError,
+};
 use crate::db::post::{
     content::Error as PostContentError, description::Error as DescriptionError,
-    tags::Error as TagsError, title::Error as TitleError,
+    tags::Error as TagsError, title::Error as TitleError, variant::Error as VariantError,
 };
+use crate::db::reaction::emoji::Error as ReactionEmojiError;
 use crate::db::user::handle::Error as HandleError;
 use crate::db::user::role::Error as UserRoleError;
+use crate::db::webhook::url::Error as WebhookUrlError;
+use actix_web::error::BlockingError;
 use actix_web::ResponseError;
 
 // I know that this is an anti-pattern, but
@@ -22,6 +30,8 @@ pub enum Error {
     Jwt(#[from] jwt_simple::Error),
     #[error("Validation error")]
     Validation,
+    #[error("Blocking task error")]
+    Blocking(#[from] BlockingError),
     #[error("Invalid user role fetched from db")]
     InvalidUserRole,
 }
@@ -68,6 +78,48 @@ impl From<DescriptionError> for Error {
     }
 }
 
+impl From<VariantError> for Error {
+    fn from(_: VariantError) -> Self {
+        Self::Validation
+    }
+}
+
+impl From<GlossaryTermError> for Error {
+    fn from(_: GlossaryTermError) -> Self {
+        Self::Validation
+    }
+}
+
+impl From<LanguageCodeError> for Error {
+    fn from(_: LanguageCodeError) -> Self {
+        Self::Validation
+    }
+}
+
+impl From<LanguageNameError> for Error {
+    fn from(_: LanguageNameError) -> Self {
+        Self::Validation
+    }
+}
+
+impl From<LanguageScriptError> for Error {
+    fn from(_: LanguageScriptError) -> Self {
+        Self::Validation
+    }
+}
+
+impl From<WebhookUrlError> for Error {
+    fn from(_: WebhookUrlError) -> Self {
+        Self::Validation
+    }
+}
+
+impl From<ReactionEmojiError> for Error {
+    fn from(_: ReactionEmojiError) -> Self {
+        Self::Validation
+    }
+}
+
 impl From<UserRoleError> for Error {
     fn from(_: UserRoleError) -> Self {
         Self::InvalidUserRole
@@ -0,0 +1,189 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status as PostStatus;
+use crate::mail::templates::{DigestPost, DigestTranslation};
+use chrono::NaiveDateTime;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub const DIGEST_INTERVAL_DAYS: u32 = 7;
+
+// Per section, the digest is a teaser and not a feed
+pub const DIGEST_SECTION_MAX_AMOUNT: u64 = 10;
+
+#[derive(Debug)]
+pub struct DueUser {
+    pub id: Id,
+    // Everything after this goes into the digest
+    pub since: NaiveDateTime,
+}
+
+pub async fn fetch_due_users(db_pool: &MySqlPool) -> Result<Vec<DueUser>> {
+    sqlx::query_as!(
+        DueUser,
+        "
+        SELECT
+            id,
+            COALESCE(digest_sent_at, NOW() - INTERVAL ? DAY) AS `since!: NaiveDateTime`
+        FROM users
+        WHERE
+            is_digest_enabled = TRUE
+            AND (
+                digest_sent_at IS NULL
+                OR digest_sent_at <= NOW() - INTERVAL ? DAY
+            )
+        ",
+        DIGEST_INTERVAL_DAYS,
+        DIGEST_INTERVAL_DAYS
+    )
+    .fetch_all(db_pool)
+    .await
+}
+
+// Posts are shown in their original language
+pub async fn fetch_followed_user_posts(
+    db_pool: &MySqlPool,
+    user_id: Id,
+    since: NaiveDateTime,
+) -> Result<Vec<DigestPost>> {
+    sqlx::query_as!(
+        DigestPost,
+        "
+        SELECT
+            post_contents.id AS post_content_id,
+            post_contents.title,
+            users.handle AS posted_by_handle
+        FROM posts
+            JOIN followers ON posts.posted_by = followers.followed_user_id
+            JOIN post_contents ON posts.source_post_content_id = post_contents.id
+            JOIN users ON posts.posted_by = users.id
+        WHERE
+            followers.follower_user_id = ?
+            AND posts.posted_at > ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+        ORDER BY posts.posted_at DESC
+        LIMIT ?
+        ",
+        user_id,
+        since,
+        DIGEST_SECTION_MAX_AMOUNT
+    )
+    .fetch_all(db_pool)
+    .await
+}
+
+pub async fn fetch_followed_tag_posts(
+    db_pool: &MySqlPool,
+    user_id: Id,
+    since: NaiveDateTime,
+) -> Result<Vec<DigestPost>> {
+    sqlx::query_as!(
+        DigestPost,
+        "
+        SELECT
+            post_contents.id AS post_content_id,
+            post_contents.title,
+            users.handle AS posted_by_handle
+        FROM posts
+            JOIN post_contents ON posts.source_post_content_id = post_contents.id
+            JOIN users ON posts.posted_by = users.id
+        WHERE
+            EXISTS (
+                SELECT
+                    post_content_tags.id
+                FROM post_content_tags
+                    JOIN user_tags ON post_content_tags.tag_id = user_tags.tag_id
+                WHERE
+                    post_content_tags.post_content_id = post_contents.id
+                    AND user_tags.user_id = ?
+            )
+            AND posts.posted_by != ?
+            AND posts.posted_at > ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+        ORDER BY posts.posted_at DESC
+        LIMIT ?
+        ",
+        user_id,
+        user_id,
+        since,
+        DIGEST_SECTION_MAX_AMOUNT
+    )
+    .fetch_all(db_pool)
+    .await
+}
+
+// Translations of the user's posts by others
+pub async fn fetch_translations(
+    db_pool: &MySqlPool,
+    user_id: Id,
+    since: NaiveDateTime,
+) -> Result<Vec<DigestTranslation>> {
+    #[derive(Debug)]
+    struct RawTranslation {
+        post_content_id: Id,
+        title: String,
+        language: String,
+        translated_by_handle: String,
+        status: String,
+    }
+
+    let raw_translations = sqlx::query_as!(
+        RawTranslation,
+        "
+        SELECT
+            post_contents.id AS post_content_id,
+            post_contents.title,
+            languages.name AS language,
+            users.handle AS translated_by_handle,
+            post_contents.status
+        FROM post_contents
+            JOIN posts ON post_contents.post_id = posts.id
+            JOIN languages ON post_contents.language_id = languages.id
+            JOIN users ON post_contents.translated_by = users.id
+        WHERE
+            posts.posted_by = ?
+            AND post_contents.translated_by != ?
+            AND post_contents.translated_at > ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+        ORDER BY post_contents.translated_at DESC
+        LIMIT ?
+        ",
+        user_id,
+        user_id,
+        since,
+        DIGEST_SECTION_MAX_AMOUNT
+    )
+    .fetch_all(db_pool)
+    .await?;
+
+    let translations = raw_translations
+        .into_iter()
+        .filter_map(|raw| {
+            Some(DigestTranslation {
+                post_content_id: raw.post_content_id,
+                title: raw.title,
+                language: raw.language,
+                translated_by_handle: raw.translated_by_handle,
+                status: PostStatus::from_str(&raw.status).ok()?,
+            })
+        })
+        .collect();
+
+    Ok(translations)
+}
+
+pub async fn update_digest_sent_at(db_pool: &MySqlPool, user_id: Id) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE users
+        SET
+            digest_sent_at = NOW()
+        WHERE id = ?
+        ",
+        user_id
+    )
+    .execute(db_pool)
+    .await
+}
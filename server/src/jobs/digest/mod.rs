@@ -0,0 +1,82 @@
+use crate::db::user::recipient::Recipient;
+use crate::jobs::lock::run_exclusively;
+use crate::mail::templates::{self, Digest, Locale};
+use crate::mail::{Mail, Mailer};
+use sqlx::mysql::MySqlPool;
+use std::sync::Arc;
+use std::time::Duration;
+
+mod db;
+use db::{
+    fetch_due_users, fetch_followed_tag_posts, fetch_followed_user_posts, fetch_translations,
+    update_digest_sent_at,
+};
+
+// Digests go out weekly, this is just how often due ones are looked for
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+// Every instance runs this job, but only one of them at a time
+const LOCK_NAME: &str = "kotoba_digest";
+
+pub async fn run(db_pool: MySqlPool, mailer: Arc<dyn Mailer>, public_url: String) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let job = send(&db_pool, mailer.as_ref(), &public_url);
+
+        if let Err(error) = run_exclusively(&db_pool, LOCK_NAME, job).await {
+            eprintln!("Failed to send digests: {error}");
+        }
+    }
+}
+
+async fn send(db_pool: &MySqlPool, mailer: &dyn Mailer, public_url: &str) -> sqlx::Result<()> {
+    for due_user in fetch_due_users(db_pool).await? {
+        let recipient = match Recipient::fetch(db_pool, due_user.id).await? {
+            Some(recipient) => recipient,
+            None => continue,
+        };
+
+        let digest = Digest {
+            followed_user_posts: fetch_followed_user_posts(db_pool, due_user.id, due_user.since)
+                .await?,
+            followed_tag_posts: fetch_followed_tag_posts(db_pool, due_user.id, due_user.since)
+                .await?,
+            translations: fetch_translations(db_pool, due_user.id, due_user.since).await?,
+        };
+
+        // A quiet week is skipped, but still counts as sent
+        if !digest.is_empty() {
+            let unsubscribe_url = format!(
+                "{public_url}/api/unsubscribe?token={}",
+                recipient.unsubscribe_token
+            );
+
+            let (subject, body) = templates::digest(
+                Locale::from_code(recipient.language_code.as_deref()),
+                &digest,
+                public_url,
+                &unsubscribe_url,
+            );
+
+            let mail = Mail {
+                to: recipient.email,
+                subject,
+                body,
+                unsubscribe_url,
+            };
+
+            // Retried on the next run
+            if let Err(error) = mailer.send(&mail).await {
+                eprintln!("Failed to email digest to user {}: {error}", due_user.id);
+                continue;
+            }
+        }
+
+        update_digest_sent_at(db_pool, due_user.id).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use sqlx::mysql::{MySqlConnection, MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+use std::future::Future;
+
+// Runs the job only if no other instance is running it right now, otherwise skips it.
+// The named MySQL lock is held by a connection,
+// so it's also released if the instance dies mid-run
+pub async fn run_exclusively<F>(db_pool: &MySqlPool, name: &str, job: F) -> Result<()>
+where
+    F: Future<Output = Result<()>>,
+{
+    let mut conn = db_pool.acquire().await?;
+
+    if !try_lock(&mut conn, name).await? {
+        return Ok(());
+    }
+
+    let result = job.await;
+
+    // Closing the connection releases the lock as well
+    if let Err(error) = unlock(&mut conn, name).await {
+        eprintln!("Failed to release lock {name}: {error}");
+        drop(conn.detach());
+    }
+
+    result
+}
+
+async fn try_lock(conn: &mut MySqlConnection, name: &str) -> Result<bool> {
+    sqlx::query_scalar!(
+        "
+        SELECT
+            COALESCE(GET_LOCK(?, 0), 0) = 1 AS `is_locked!: bool`
+        ",
+        name
+    )
+    .fetch_one(conn)
+    .await
+}
+
+async fn unlock(conn: &mut MySqlConnection, name: &str) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DO RELEASE_LOCK(?)
+        ",
+        name
+    )
+    .execute(conn)
+    .await
+}
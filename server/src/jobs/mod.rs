@@ -0,0 +1,25 @@
+use crate::mail::{self, Mailer};
+use sqlx::mysql::MySqlPool;
+use std::sync::Arc;
+
+pub mod digest;
+mod lock;
+pub mod notification_emails;
+pub mod purge;
+pub mod webhooks;
+
+pub fn spawn(db_pool: &MySqlPool, mailer: Option<Arc<dyn Mailer>>) {
+    tokio::spawn(purge::run(db_pool.clone()));
+    tokio::spawn(webhooks::run(db_pool.clone()));
+
+    if let Some(mailer) = mailer {
+        let public_url = mail::public_url_from_env();
+
+        tokio::spawn(notification_emails::run(
+            db_pool.clone(),
+            mailer.clone(),
+            public_url.clone(),
+        ));
+        tokio::spawn(digest::run(db_pool.clone(), mailer, public_url));
+    }
+}
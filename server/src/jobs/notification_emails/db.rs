@@ -0,0 +1,60 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+#[derive(Debug)]
+pub struct PendingNotification {
+    pub id: Id,
+    pub user_id: Id,
+    pub kind: String,
+    pub actor_id: Id,
+    pub actor_handle: String,
+    pub post_content_id: Option<Id>,
+    pub title: Option<String>,
+}
+
+// Only kinds the user wants emailed, and only fresh ones,
+// so turning emails on doesn't flood the inbox with old news.
+// Read notifications were already seen on the site
+pub async fn fetch_pending_notifications(db_pool: &MySqlPool) -> Result<Vec<PendingNotification>> {
+    sqlx::query_as!(
+        PendingNotification,
+        "
+        SELECT
+            notifications.id,
+            notifications.user_id,
+            notifications.kind,
+            notifications.actor_id,
+            users.handle AS actor_handle,
+            notifications.post_content_id,
+            post_contents.title AS `title?`
+        FROM notifications
+            JOIN notification_preferences ON notifications.user_id = notification_preferences.user_id
+                AND notifications.kind = notification_preferences.kind
+            JOIN users ON notifications.actor_id = users.id
+            LEFT JOIN post_contents ON notifications.post_content_id = post_contents.id
+        WHERE
+            notification_preferences.is_emailed = TRUE
+            AND notifications.emailed_at IS NULL
+            AND notifications.read_at IS NULL
+            AND notifications.created_at > NOW() - INTERVAL 1 DAY
+        ORDER BY notifications.id
+        "
+    )
+    .fetch_all(db_pool)
+    .await
+}
+
+pub async fn mark_as_emailed(db_pool: &MySqlPool, notification_id: Id) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE notifications
+        SET
+            emailed_at = NOW()
+        WHERE id = ?
+        ",
+        notification_id
+    )
+    .execute(db_pool)
+    .await
+}
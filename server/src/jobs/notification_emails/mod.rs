@@ -0,0 +1,77 @@
+use crate::db::notification::kind::Kind as NotificationKind;
+use crate::db::user::recipient::Recipient;
+use crate::jobs::lock::run_exclusively;
+use crate::mail::templates::{self, Locale};
+use crate::mail::{Mail, Mailer};
+use sqlx::mysql::MySqlPool;
+use std::sync::Arc;
+use std::time::Duration;
+
+mod db;
+use db::{fetch_pending_notifications, mark_as_emailed};
+
+const SEND_INTERVAL: Duration = Duration::from_secs(60);
+
+// Every instance runs this job, but only one of them at a time
+const LOCK_NAME: &str = "kotoba_notification_emails";
+
+pub async fn run(db_pool: MySqlPool, mailer: Arc<dyn Mailer>, public_url: String) {
+    let mut interval = tokio::time::interval(SEND_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let job = send(&db_pool, mailer.as_ref(), &public_url);
+
+        if let Err(error) = run_exclusively(&db_pool, LOCK_NAME, job).await {
+            eprintln!("Failed to send notification emails: {error}");
+        }
+    }
+}
+
+async fn send(db_pool: &MySqlPool, mailer: &dyn Mailer, public_url: &str) -> sqlx::Result<()> {
+    for notification in fetch_pending_notifications(db_pool).await? {
+        let recipient = match Recipient::fetch(db_pool, notification.user_id).await? {
+            Some(recipient) => recipient,
+            None => continue,
+        };
+
+        // TODO: Better error handling
+        let kind = NotificationKind::from_str(&notification.kind).unwrap();
+
+        let link = match notification.post_content_id {
+            Some(post_content_id) => format!("{public_url}/post/{post_content_id}"),
+            None => format!("{public_url}/user/{}", notification.actor_id),
+        };
+        let unsubscribe_url = format!(
+            "{public_url}/api/unsubscribe?token={}",
+            recipient.unsubscribe_token
+        );
+
+        let (subject, body) = templates::notification(
+            Locale::from_code(recipient.language_code.as_deref()),
+            kind,
+            &notification.actor_handle,
+            notification.title.as_deref(),
+            &link,
+            &unsubscribe_url,
+        );
+
+        let mail = Mail {
+            to: recipient.email,
+            subject,
+            body,
+            unsubscribe_url,
+        };
+
+        // Failed ones are retried on the next run until they get too old
+        if let Err(error) = mailer.send(&mail).await {
+            eprintln!("Failed to email notification {}: {error}", notification.id);
+            continue;
+        }
+
+        mark_as_emailed(db_pool, notification.id).await?;
+    }
+
+    Ok(())
+}
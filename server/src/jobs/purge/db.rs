@@ -0,0 +1,462 @@
+use crate::db::id::{Id, IdRow};
+use crate::db::post::draft::DRAFT_WINDOW_HOURS;
+use crate::db::post::RESTORE_WINDOW_DAYS;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+pub async fn fetch_expired_post_ids(db_pool: &MySqlPool) -> Result<Vec<Id>> {
+    sqlx::query_as!(
+        IdRow,
+        "
+        SELECT
+            id
+        FROM posts
+        WHERE
+            deleted_at <= NOW() - INTERVAL ? DAY
+        ",
+        RESTORE_WINDOW_DAYS
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|id_rows| id_rows.into_iter().map(|id_row| id_row.id).collect())
+}
+
+pub async fn fetch_expired_post_content_ids(db_pool: &MySqlPool) -> Result<Vec<Id>> {
+    sqlx::query_as!(
+        IdRow,
+        "
+        SELECT
+            id
+        FROM post_contents
+        WHERE
+            deleted_at <= NOW() - INTERVAL ? DAY
+        ",
+        RESTORE_WINDOW_DAYS
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|id_rows| id_rows.into_iter().map(|id_row| id_row.id).collect())
+}
+
+pub async fn purge_post(tx: &mut Transaction<'_, MySql>, post_id: Id) -> Result<()> {
+    sqlx::query!(
+        "
+        UPDATE posts
+        SET
+            source_post_content_id = NULL
+        WHERE id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let post_content_ids: Vec<Id> = sqlx::query_as!(
+        IdRow,
+        "
+        SELECT
+            id
+        FROM post_contents
+        WHERE
+            post_id = ?
+        ",
+        post_id
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .map(|id_row| id_row.id)
+    .collect();
+
+    for post_content_id in post_content_ids {
+        purge_post_content(tx, post_content_id).await?;
+    }
+
+    sqlx::query!(
+        "
+        DELETE FROM bookmarks
+        WHERE post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM reactions
+        WHERE post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM user_posts
+        WHERE post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM translation_claims
+        WHERE post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM translation_drafts
+        WHERE post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM translation_requests
+        WHERE post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE glossary_term_translations FROM glossary_term_translations
+            JOIN glossary_terms ON glossary_term_translations.glossary_term_id = glossary_terms.id
+        WHERE glossary_terms.post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM glossary_terms
+        WHERE post_id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM posts
+        WHERE id = ?
+        ",
+        post_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn purge_post_content(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+) -> Result<()> {
+    // `comments.reply_to` is `ON DELETE RESTRICT`,
+    // so reply chains have to be unlinked first
+    sqlx::query!(
+        "
+        UPDATE comments
+        SET
+            reply_to = NULL
+        WHERE
+            post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Including replies from other translations of the post.
+    // MySQL can't select from the table being updated directly,
+    // hence the derived table
+    sqlx::query!(
+        "
+        UPDATE comments
+        SET
+            reply_to = NULL
+        WHERE
+            reply_to IN (
+                SELECT
+                    id
+                FROM (
+                    SELECT
+                        id
+                    FROM comments
+                    WHERE post_content_id = ?
+                ) AS purged_comments
+            )
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM mentions
+        WHERE
+            post_content_id = ?
+            OR comment_id IN (
+                SELECT
+                    id
+                FROM comments
+                WHERE post_content_id = ?
+            )
+        ",
+        post_content_id,
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM notifications
+        WHERE
+            post_content_id = ?
+            OR comment_id IN (
+                SELECT
+                    id
+                FROM comments
+                WHERE post_content_id = ?
+            )
+        ",
+        post_content_id,
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM reactions
+        WHERE
+            comment_id IN (
+                SELECT
+                    id
+                FROM comments
+                WHERE post_content_id = ?
+            )
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM comment_revisions
+        WHERE
+            comment_id IN (
+                SELECT
+                    id
+                FROM comments
+                WHERE post_content_id = ?
+            )
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM comments
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        UPDATE translation_reviews
+        SET
+            reply_to = NULL
+        WHERE
+            post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM translation_reviews
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM translation_suggestions
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        UPDATE translation_requests
+        SET
+            post_content_id = NULL
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM translation_memory
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM translation_votes
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM post_content_tags
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Translations of this content keep pointing
+    // at its revisions through `source_revision_id`
+    sqlx::query!(
+        "
+        UPDATE post_contents
+            JOIN post_content_revisions ON post_contents.source_revision_id = post_content_revisions.id
+        SET
+            post_contents.source_revision_id = NULL
+        WHERE post_content_revisions.post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE post_content_revision_tags FROM post_content_revision_tags
+            JOIN post_content_revisions ON post_content_revision_tags.post_content_revision_id = post_content_revisions.id
+        WHERE post_content_revisions.post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM post_content_revisions
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM post_contents
+        WHERE id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn delete_expired_claims(db_pool: &MySqlPool) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_claims
+        WHERE expires_at <= NOW()
+        "
+    )
+    .execute(db_pool)
+    .await
+}
+
+pub async fn delete_old_drafts(db_pool: &MySqlPool) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_drafts
+        WHERE drafted_at <= NOW() - INTERVAL ? HOUR
+        ",
+        DRAFT_WINDOW_HOURS
+    )
+    .execute(db_pool)
+    .await
+}
+
+// Every instance has long polled them by then
+pub async fn delete_old_realtime_events(db_pool: &MySqlPool) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM realtime_events
+        WHERE created_at <= NOW() - INTERVAL 1 HOUR
+        "
+    )
+    .execute(db_pool)
+    .await
+}
+
+// Finished ones only, both delivered and out of attempts
+pub async fn delete_old_webhook_deliveries(db_pool: &MySqlPool) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM webhook_deliveries
+        WHERE
+            next_attempt_at IS NULL
+            AND created_at <= NOW() - INTERVAL 30 DAY
+        "
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,90 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySqlPool;
+use std::time::Duration;
+
+mod db;
+use db::{
+    delete_expired_claims, delete_old_drafts, delete_old_realtime_events,
+    delete_old_webhook_deliveries, fetch_expired_post_content_ids, fetch_expired_post_ids,
+    purge_post, purge_post_content,
+};
+
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+pub async fn run(db_pool: MySqlPool) {
+    let mut interval = tokio::time::interval(PURGE_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        purge(&db_pool).await;
+    }
+}
+
+// Every step is independent, so one failure
+// only gets logged and doesn't hold back the rest
+async fn purge(db_pool: &MySqlPool) {
+    if let Err(error) = purge_posts(db_pool).await {
+        eprintln!("Failed to fetch expired posts: {error}");
+    }
+
+    if let Err(error) = purge_post_contents(db_pool).await {
+        eprintln!("Failed to fetch expired translations: {error}");
+    }
+
+    // Expired claims are already ignored everywhere,
+    // this just keeps the table from growing
+    if let Err(error) = delete_expired_claims(db_pool).await {
+        eprintln!("Failed to delete expired claims: {error}");
+    }
+
+    if let Err(error) = delete_old_drafts(db_pool).await {
+        eprintln!("Failed to delete old drafts: {error}");
+    }
+
+    if let Err(error) = delete_old_realtime_events(db_pool).await {
+        eprintln!("Failed to delete old realtime events: {error}");
+    }
+
+    if let Err(error) = delete_old_webhook_deliveries(db_pool).await {
+        eprintln!("Failed to delete old webhook deliveries: {error}");
+    }
+}
+
+// Every post (or translation) is purged in its own transaction,
+// a failing one is retried on the next run
+async fn purge_posts(db_pool: &MySqlPool) -> sqlx::Result<()> {
+    for post_id in fetch_expired_post_ids(db_pool).await? {
+        if let Err(error) = purge_post_in_tx(db_pool, post_id).await {
+            eprintln!("Failed to purge post {post_id}: {error}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn purge_post_contents(db_pool: &MySqlPool) -> sqlx::Result<()> {
+    for post_content_id in fetch_expired_post_content_ids(db_pool).await? {
+        if let Err(error) = purge_post_content_in_tx(db_pool, post_content_id).await {
+            eprintln!("Failed to purge translation {post_content_id}: {error}");
+        }
+    }
+
+    Ok(())
+}
+
+async fn purge_post_in_tx(db_pool: &MySqlPool, post_id: Id) -> sqlx::Result<()> {
+    let mut tx = db_pool.begin().await?;
+
+    purge_post(&mut tx, post_id).await?;
+
+    tx.commit().await
+}
+
+async fn purge_post_content_in_tx(db_pool: &MySqlPool, post_content_id: Id) -> sqlx::Result<()> {
+    let mut tx = db_pool.begin().await?;
+
+    purge_post_content(&mut tx, post_content_id).await?;
+
+    tx.commit().await
+}
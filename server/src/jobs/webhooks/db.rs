@@ -0,0 +1,117 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+// Deliveries are sent concurrently, a batch at a time
+pub const DELIVERY_BATCH_MAX_AMOUNT: u64 = 20;
+
+// How long a claimed delivery is left to the instance that claimed it,
+// if it dies before recording the outcome the delivery is picked up again after this
+pub const CLAIM_SECS: i64 = 5 * 60;
+
+#[derive(Debug)]
+pub struct DueDelivery {
+    pub id: Id,
+    pub event: String,
+    pub payload: String,
+    pub attempt_count: i32,
+    pub url: String,
+    pub secret: String,
+}
+
+// Rows claimed by another instance are locked until it commits and skipped here,
+// only the deliveries are locked so other deliveries of the same webhook are still free
+pub async fn fetch_due_deliveries(tx: &mut Transaction<'_, MySql>) -> Result<Vec<DueDelivery>> {
+    sqlx::query_as!(
+        DueDelivery,
+        "
+        SELECT
+            webhook_deliveries.id,
+            webhook_deliveries.event,
+            webhook_deliveries.payload,
+            webhook_deliveries.attempt_count,
+            webhooks.url,
+            webhooks.secret
+        FROM webhook_deliveries
+            JOIN webhooks ON webhook_deliveries.webhook_id = webhooks.id
+        WHERE
+            webhook_deliveries.delivered_at IS NULL
+            AND webhook_deliveries.next_attempt_at <= NOW()
+        ORDER BY webhook_deliveries.id
+        LIMIT ?
+        FOR UPDATE OF webhook_deliveries SKIP LOCKED
+        ",
+        DELIVERY_BATCH_MAX_AMOUNT
+    )
+    .fetch_all(&mut *tx)
+    .await
+}
+
+pub async fn claim_delivery(
+    tx: &mut Transaction<'_, MySql>,
+    delivery_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE webhook_deliveries
+        SET
+            next_attempt_at = NOW() + INTERVAL ? SECOND
+        WHERE id = ?
+        ",
+        CLAIM_SECS,
+        delivery_id
+    )
+    .execute(&mut *tx)
+    .await
+}
+
+pub async fn mark_as_delivered(
+    db_pool: &MySqlPool,
+    delivery_id: Id,
+    status_code: i32,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE webhook_deliveries
+        SET
+            attempt_count = attempt_count + 1,
+            next_attempt_at = NULL,
+            last_status_code = ?,
+            last_error = NULL,
+            delivered_at = NOW()
+        WHERE id = ?
+        ",
+        status_code,
+        delivery_id
+    )
+    .execute(db_pool)
+    .await
+}
+
+// Without a retry delay next_attempt_at ends up NULL,
+// so a delivery that ran out of attempts is never picked up again
+pub async fn mark_as_failed(
+    db_pool: &MySqlPool,
+    delivery_id: Id,
+    status_code: Option<i32>,
+    error: &str,
+    retry_delay_secs: Option<i64>,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE webhook_deliveries
+        SET
+            attempt_count = attempt_count + 1,
+            next_attempt_at = NOW() + INTERVAL ? SECOND,
+            last_status_code = ?,
+            last_error = LEFT(?, 1024)
+        WHERE id = ?
+        ",
+        retry_delay_secs,
+        status_code,
+        error,
+        delivery_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,140 @@
+use crate::db::webhook::url::{resolve_public, Target};
+use chrono::Utc;
+use futures::future;
+use reqwest::redirect::Policy;
+use reqwest::Client;
+use sqlx::mysql::MySqlPool;
+use std::time::Duration;
+
+mod db;
+use db::{
+    claim_delivery, fetch_due_deliveries, mark_as_delivered, mark_as_failed, DueDelivery,
+    DELIVERY_BATCH_MAX_AMOUNT,
+};
+
+mod signature;
+
+const DELIVERY_INTERVAL: Duration = Duration::from_secs(10);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// With the first retry after 30 seconds and doubling from there,
+// the last attempt happens a little over an hour after the first
+const MAX_ATTEMPTS: i32 = 8;
+const BASE_RETRY_DELAY_SECS: i64 = 30;
+
+pub async fn run(db_pool: MySqlPool) {
+    let mut interval = tokio::time::interval(DELIVERY_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(error) = deliver(&db_pool).await {
+            eprintln!("Failed to deliver webhooks: {error}");
+        }
+    }
+}
+
+// Every instance runs this, so deliveries are claimed before being sent.
+// A full batch means more are probably due, so the next one follows right away
+async fn deliver(db_pool: &MySqlPool) -> sqlx::Result<()> {
+    loop {
+        let deliveries = claim_due_deliveries(db_pool).await?;
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        let is_full_batch = deliveries.len() as u64 == DELIVERY_BATCH_MAX_AMOUNT;
+
+        // Concurrently, so a slow endpoint only holds up its own delivery
+        let results = future::join_all(deliveries.iter().map(send)).await;
+
+        for (delivery, result) in deliveries.iter().zip(results) {
+            record(db_pool, delivery, result).await?;
+        }
+
+        if !is_full_batch {
+            return Ok(());
+        }
+    }
+}
+
+async fn claim_due_deliveries(db_pool: &MySqlPool) -> sqlx::Result<Vec<DueDelivery>> {
+    let mut tx = db_pool.begin().await?;
+
+    let deliveries = fetch_due_deliveries(&mut tx).await?;
+    for delivery in &deliveries {
+        claim_delivery(&mut tx, delivery.id).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(deliveries)
+}
+
+async fn record(
+    db_pool: &MySqlPool,
+    delivery: &DueDelivery,
+    result: Result<i32, (Option<i32>, String)>,
+) -> sqlx::Result<()> {
+    match result {
+        Ok(status_code) => {
+            mark_as_delivered(db_pool, delivery.id, status_code).await?;
+        }
+        Err((status_code, error)) => {
+            let attempt_count = delivery.attempt_count + 1;
+            let retry_delay = (attempt_count < MAX_ATTEMPTS)
+                .then(|| BASE_RETRY_DELAY_SECS << (attempt_count - 1));
+
+            mark_as_failed(db_pool, delivery.id, status_code, &error, retry_delay).await?;
+        }
+    }
+
+    Ok(())
+}
+
+// Anything but a 2xx counts as a failure, redirects included
+async fn send(delivery: &DueDelivery) -> Result<i32, (Option<i32>, String)> {
+    let target = resolve_public(&delivery.url)
+        .await
+        .map_err(|error| (None, format!("Refused to deliver: {error:?}")))?;
+    let client = client(&target).map_err(|error| (None, error.to_string()))?;
+
+    let timestamp = Utc::now().timestamp();
+
+    let response = client
+        .post(&delivery.url)
+        .header("Content-Type", "application/json")
+        .header("X-Kotoba-Event", &delivery.event)
+        .header("X-Kotoba-Delivery", delivery.id.to_string())
+        .header(
+            "X-Kotoba-Signature",
+            signature::sign(&delivery.secret, timestamp, &delivery.payload),
+        )
+        .body(delivery.payload.clone())
+        .send()
+        .await
+        .map_err(|error| (None, error.to_string()))?;
+
+    let status = response.status();
+    let status_code = status.as_u16() as i32;
+
+    if !status.is_success() {
+        return Err((Some(status_code), status.to_string()));
+    }
+
+    Ok(status_code)
+}
+
+// Connects to the address that was just checked,
+// so the domain can't resolve somewhere else in between
+fn client(target: &Target) -> reqwest::Result<Client> {
+    let mut builder = Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .redirect(Policy::none());
+
+    if let Some(domain) = &target.domain {
+        builder = builder.resolve(domain, target.address);
+    }
+
+    builder.build()
+}
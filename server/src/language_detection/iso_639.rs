@@ -0,0 +1,96 @@
+// ISO 639-3 codes of the languages whatlang can detect
+// mapped to the ISO 639-1 codes `languages.code` starts with
+const CODES: [(&'static str, &'static str); 69] = [
+    ("afr", "af"),
+    ("aka", "ak"),
+    ("amh", "am"),
+    ("ara", "ar"),
+    ("aze", "az"),
+    ("bel", "be"),
+    ("ben", "bn"),
+    ("bul", "bg"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("cmn", "zh"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("est", "et"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("guj", "gu"),
+    ("heb", "he"),
+    ("hin", "hi"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ind", "id"),
+    ("ita", "it"),
+    ("jav", "jv"),
+    ("jpn", "ja"),
+    ("kan", "kn"),
+    ("kat", "ka"),
+    ("khm", "km"),
+    ("kor", "ko"),
+    ("lat", "la"),
+    ("lav", "lv"),
+    ("lit", "lt"),
+    ("mal", "ml"),
+    ("mar", "mr"),
+    ("mkd", "mk"),
+    ("mya", "my"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nob", "nb"),
+    ("ori", "or"),
+    ("pan", "pa"),
+    ("pes", "fa"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("ron", "ro"),
+    ("rus", "ru"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slv", "sl"),
+    ("sna", "sn"),
+    ("spa", "es"),
+    ("srp", "sr"),
+    ("swe", "sv"),
+    ("tam", "ta"),
+    ("tel", "te"),
+    ("tgl", "tl"),
+    ("tha", "th"),
+    ("tuk", "tk"),
+    ("tur", "tr"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("uzb", "uz"),
+    ("vie", "vi"),
+    ("yid", "yi"),
+    ("zul", "zu"),
+];
+
+// Languages close enough to be mistaken for one another,
+// disagreeing within a group is not worth bothering anyone
+const RELATED: [&'static [&'static str]; 4] = [
+    &["no", "nb", "nn"],
+    &["sr", "hr", "bs"],
+    &["id", "ms"],
+    &["tl", "fil"],
+];
+
+pub fn to_639_1(code: &str) -> Option<&'static str> {
+    CODES
+        .iter()
+        .find(|(iso_639_3, _)| *iso_639_3 == code)
+        .map(|(_, iso_639_1)| *iso_639_1)
+}
+
+pub fn are_related(a: &str, b: &str) -> bool {
+    a == b
+        || RELATED
+            .iter()
+            .any(|group| group.contains(&a) && group.contains(&b))
+}
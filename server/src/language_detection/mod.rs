@@ -0,0 +1,95 @@
+use crate::db::id::Id;
+use crate::db::language::{fetch_language_by_code, fetch_language_code, Language};
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+mod iso_639;
+
+pub const MODE_WARN: &'static str = "warn";
+pub const MODE_REJECT: &'static str = "reject";
+pub const MODE_OFF: &'static str = "off";
+
+// What to do when the submitted content
+// doesn't look like it's in the language it was filed under
+#[derive(Debug, Copy, Clone)]
+pub enum Mode {
+    Warn,
+    Reject,
+    Off,
+}
+
+impl Mode {
+    // Warns by default if `$LANGUAGE_DETECTION` is not set
+    pub fn from_env() -> Self {
+        let mode = match std::env::var("LANGUAGE_DETECTION") {
+            Ok(mode) => mode,
+            Err(_) => return Self::Warn,
+        };
+
+        match mode.as_str() {
+            MODE_WARN => Self::Warn,
+            MODE_REJECT => Self::Reject,
+            MODE_OFF => Self::Off,
+            _ => panic!("Unknown $LANGUAGE_DETECTION `{mode}`"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Mismatch {
+    // ISO 639-1 code of the detected language
+    pub detected_code: String,
+    // The language to file the content under instead,
+    // if there is one for the detected code
+    pub suggested_language: Option<Language>,
+}
+
+// ISO 639-1 code of the language `text` is written in,
+// `None` if it is too short or ambiguous to tell
+pub fn detect(text: &str) -> Option<&'static str> {
+    let info = whatlang::detect(text)?;
+
+    if !info.is_reliable() {
+        return None;
+    }
+
+    iso_639::to_639_1(info.lang().code())
+}
+
+pub async fn check(
+    db_pool: &MySqlPool,
+    mode: Mode,
+    language_id: Id,
+    texts: &[&str],
+) -> Result<Option<Mismatch>> {
+    if matches!(mode, Mode::Off) {
+        return Ok(None);
+    }
+
+    let detected_code = match detect(&texts.join("\n\n")) {
+        Some(detected_code) => detected_code,
+        None => return Ok(None),
+    };
+
+    let language_code = match fetch_language_code(db_pool, language_id).await? {
+        Some(language_code) => language_code.to_ascii_lowercase(),
+        None => return Ok(None),
+    };
+
+    let primary_language_code = language_code
+        .split('-')
+        .next()
+        .expect("`split` always yields at least one item");
+
+    if iso_639::are_related(primary_language_code, detected_code) {
+        return Ok(None);
+    }
+
+    let suggested_language = fetch_language_by_code(db_pool, detected_code).await?;
+
+    Ok(Some(Mismatch {
+        detected_code: detected_code.to_owned(),
+        suggested_language,
+    }))
+}
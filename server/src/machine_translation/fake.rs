@@ -0,0 +1,22 @@
+use super::{Error, Translator};
+use futures::future::{BoxFuture, FutureExt};
+
+// Always gives the same output for the same input,
+// so drafting can be worked on without a real provider
+pub struct Fake;
+
+impl Translator for Fake {
+    fn translate<'a>(
+        &'a self,
+        texts: &'a [String],
+        _source: &'a str,
+        target: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<String>, Error>> {
+        let translated = texts
+            .iter()
+            .map(|text| format!("[{target}] {text}"))
+            .collect();
+
+        async move { Ok(translated) }.boxed()
+    }
+}
@@ -0,0 +1,80 @@
+use super::{Error, Translator};
+use futures::future::{BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Drafts are requested while the translator waits,
+// a stuck provider shouldn't hold the request forever
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+// https://github.com/LibreTranslate/LibreTranslate
+pub struct LibreTranslate {
+    client: reqwest::Client,
+    url: String,
+    api_key: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Request<'a> {
+    q: &'a [String],
+    source: &'a str,
+    target: &'a str,
+    format: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_key: Option<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    #[serde(rename = "translatedText")]
+    translated_text: Vec<String>,
+}
+
+impl LibreTranslate {
+    pub fn new(url: String, api_key: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("Failed to initialize the TLS backend"),
+            url: url.trim_end_matches('/').to_owned(),
+            api_key,
+        }
+    }
+}
+
+impl Translator for LibreTranslate {
+    fn translate<'a>(
+        &'a self,
+        texts: &'a [String],
+        source: &'a str,
+        target: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<String>, Error>> {
+        async move {
+            let request = Request {
+                q: texts,
+                source,
+                target,
+                format: "text",
+                api_key: self.api_key.as_deref(),
+            };
+
+            let response: Response = self
+                .client
+                .post(format!("{}/translate", self.url))
+                .json(&request)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+
+            if response.translated_text.len() != texts.len() {
+                return Err(Error::InvalidResponse);
+            }
+
+            Ok(response.translated_text)
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,45 @@
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+pub mod fake;
+use fake::Fake;
+
+pub mod libre_translate;
+use libre_translate::LibreTranslate;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("reqwest error")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("Provider returned a different amount of texts than it was given")]
+    InvalidResponse,
+}
+
+// Languages are passed as codes (`en`, `uk`, ...),
+// texts come back translated in the same order
+pub trait Translator: Send + Sync {
+    fn translate<'a>(
+        &'a self,
+        texts: &'a [String],
+        source: &'a str,
+        target: &'a str,
+    ) -> BoxFuture<'a, Result<Vec<String>, Error>>;
+}
+
+// Drafting is disabled if `$MT_PROVIDER` is not set
+pub fn from_env() -> Option<Arc<dyn Translator>> {
+    let provider = std::env::var("MT_PROVIDER").ok()?;
+
+    let translator: Arc<dyn Translator> = match provider.as_str() {
+        "libretranslate" => {
+            let url = std::env::var("MT_URL").expect("$MT_URL env var is not set");
+            let api_key = std::env::var("MT_API_KEY").ok();
+
+            Arc::new(LibreTranslate::new(url, api_key))
+        }
+        "fake" => Arc::new(Fake),
+        _ => panic!("Unknown $MT_PROVIDER `{provider}`"),
+    };
+
+    Some(translator)
+}
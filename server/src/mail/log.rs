@@ -0,0 +1,17 @@
+use super::{Error, Mail, Mailer};
+use futures::future::{BoxFuture, FutureExt};
+
+// Prints emails instead of sending them,
+// so templates can be worked on without an SMTP server
+pub struct Log;
+
+impl Mailer for Log {
+    fn send<'a>(&'a self, mail: &'a Mail) -> BoxFuture<'a, Result<(), Error>> {
+        println!(
+            "To: {}\nSubject: {}\nList-Unsubscribe: <{}>\n\n{}\n",
+            mail.to, mail.subject, mail.unsubscribe_url, mail.body
+        );
+
+        async { Ok(()) }.boxed()
+    }
+}
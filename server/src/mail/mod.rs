@@ -0,0 +1,58 @@
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+pub mod log;
+use self::log::Log;
+
+pub mod smtp;
+use smtp::Smtp;
+
+pub mod templates;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("lettre error")]
+    Lettre(#[from] lettre::error::Error),
+    #[error("SMTP error")]
+    Smtp(#[from] lettre::transport::smtp::Error),
+    #[error("Invalid email address")]
+    Address(#[from] lettre::address::AddressError),
+}
+
+// Plain text only
+#[derive(Debug)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+    // Sent as `List-Unsubscribe` along with `List-Unsubscribe-Post`,
+    // so mail clients can offer one-click unsubscribing (RFC 8058)
+    pub unsubscribe_url: String,
+}
+
+pub trait Mailer: Send + Sync {
+    fn send<'a>(&'a self, mail: &'a Mail) -> BoxFuture<'a, Result<(), Error>>;
+}
+
+// Emails are disabled if `$MAIL_TRANSPORT` is not set
+pub fn from_env() -> Option<Arc<dyn Mailer>> {
+    let transport = std::env::var("MAIL_TRANSPORT").ok()?;
+
+    let mailer: Arc<dyn Mailer> = match transport.as_str() {
+        "smtp" => {
+            let url = std::env::var("MAIL_SMTP_URL").expect("$MAIL_SMTP_URL env var is not set");
+            let from = std::env::var("MAIL_FROM").expect("$MAIL_FROM env var is not set");
+
+            Arc::new(Smtp::new(&url, &from))
+        }
+        "log" => Arc::new(Log),
+        _ => panic!("Unknown $MAIL_TRANSPORT `{transport}`"),
+    };
+
+    Some(mailer)
+}
+
+// Where links in emails point to
+pub fn public_url_from_env() -> String {
+    std::env::var("PUBLIC_URL").unwrap_or_else(|_| "http://localhost:3000".to_owned())
+}
@@ -0,0 +1,42 @@
+use super::Strings;
+use crate::db::notification::kind::Kind as NotificationKind;
+use crate::db::post::status::Status as PostStatus;
+
+pub const STRINGS: Strings = Strings {
+    notification,
+    status,
+    by: "by",
+    digest_subject: "Your weekly kotoba digest",
+    followed_user_posts: "New posts from people you follow",
+    followed_tag_posts: "New posts in tags you follow",
+    translations: "Translation activity on your posts",
+    unsubscribe: "Unsubscribe from these emails:",
+};
+
+fn notification(kind: NotificationKind, actor: &str) -> String {
+    match kind {
+        NotificationKind::Mention => format!("@{actor} mentioned you"),
+        NotificationKind::Reply => format!("@{actor} replied to your comment"),
+        NotificationKind::Follow => format!("@{actor} started following you"),
+        NotificationKind::TranslationSubmitted => {
+            format!("@{actor} submitted a translation of your post")
+        }
+        NotificationKind::TranslationApproved => format!("@{actor} approved your translation"),
+        NotificationKind::TranslationDenied => format!("@{actor} denied your translation"),
+        NotificationKind::ChangesRequested => {
+            format!("@{actor} requested changes to your translation")
+        }
+        NotificationKind::TranslationRequestFulfilled => {
+            format!("@{actor} translated a post you requested")
+        }
+    }
+}
+
+fn status(status: PostStatus) -> &'static str {
+    match status {
+        PostStatus::Pending => "pending",
+        PostStatus::Approved => "approved",
+        PostStatus::Denied => "denied",
+        PostStatus::ChangesRequested => "changes requested",
+    }
+}
@@ -0,0 +1,153 @@
+use crate::db::id::Id;
+use crate::db::notification::kind::Kind as NotificationKind;
+use crate::db::post::status::Status as PostStatus;
+
+mod en;
+mod uk;
+
+// Everything an email says, in one language
+pub struct Strings {
+    pub notification: fn(NotificationKind, &str) -> String,
+    pub status: fn(PostStatus) -> &'static str,
+    pub by: &'static str,
+    pub digest_subject: &'static str,
+    pub followed_user_posts: &'static str,
+    pub followed_tag_posts: &'static str,
+    pub translations: &'static str,
+    pub unsubscribe: &'static str,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Locale {
+    En,
+    Uk,
+}
+
+impl Locale {
+    // Takes the code of the first language the user prefers,
+    // regional variants like `uk-UA` fall back to the base language
+    pub fn from_code(code: Option<&str>) -> Self {
+        match code.and_then(|code| code.split('-').next()) {
+            Some("uk") => Self::Uk,
+            _ => Self::En,
+        }
+    }
+
+    fn strings(&self) -> &'static Strings {
+        match self {
+            Self::En => &en::STRINGS,
+            Self::Uk => &uk::STRINGS,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DigestPost {
+    pub post_content_id: Id,
+    pub title: String,
+    pub posted_by_handle: String,
+}
+
+#[derive(Debug)]
+pub struct DigestTranslation {
+    pub post_content_id: Id,
+    pub title: String,
+    pub language: String,
+    pub translated_by_handle: String,
+    pub status: PostStatus,
+}
+
+#[derive(Debug)]
+pub struct Digest {
+    pub followed_user_posts: Vec<DigestPost>,
+    pub followed_tag_posts: Vec<DigestPost>,
+    pub translations: Vec<DigestTranslation>,
+}
+
+impl Digest {
+    pub fn is_empty(&self) -> bool {
+        self.followed_user_posts.is_empty()
+            && self.followed_tag_posts.is_empty()
+            && self.translations.is_empty()
+    }
+}
+
+// Returns the subject and the body
+pub fn notification(
+    locale: Locale,
+    kind: NotificationKind,
+    actor_handle: &str,
+    title: Option<&str>,
+    link: &str,
+    unsubscribe_url: &str,
+) -> (String, String) {
+    let strings = locale.strings();
+
+    let subject = (strings.notification)(kind, actor_handle);
+
+    let mut body = format!("{subject}\n\n");
+    if let Some(title) = title {
+        body += &format!("{title}\n");
+    }
+    body += &format!("{link}\n");
+    body += &footer(strings, unsubscribe_url);
+
+    (subject, body)
+}
+
+// Returns the subject and the body
+pub fn digest(
+    locale: Locale,
+    digest: &Digest,
+    public_url: &str,
+    unsubscribe_url: &str,
+) -> (String, String) {
+    let strings = locale.strings();
+
+    let mut body = String::new();
+
+    for (heading, posts) in [
+        (strings.followed_user_posts, &digest.followed_user_posts),
+        (strings.followed_tag_posts, &digest.followed_tag_posts),
+    ] {
+        if posts.is_empty() {
+            continue;
+        }
+
+        body += &format!("{heading}\n\n");
+        for post in posts {
+            body += &format!(
+                "- {} {} @{}\n  {public_url}/post/{}\n",
+                post.title, strings.by, post.posted_by_handle, post.post_content_id
+            );
+        }
+        body += "\n";
+    }
+
+    if !digest.translations.is_empty() {
+        body += &format!("{}\n\n", strings.translations);
+        for translation in &digest.translations {
+            body += &format!(
+                "- {} ({}) {} @{}: {}\n  {public_url}/post/{}\n",
+                translation.title,
+                translation.language,
+                strings.by,
+                translation.translated_by_handle,
+                (strings.status)(translation.status),
+                translation.post_content_id
+            );
+        }
+        body += "\n";
+    }
+
+    // Sections are separated by a blank line, the footer brings its own
+    body.truncate(body.trim_end().len());
+    body += "\n";
+    body += &footer(strings, unsubscribe_url);
+
+    (strings.digest_subject.to_owned(), body)
+}
+
+fn footer(strings: &Strings, unsubscribe_url: &str) -> String {
+    format!("\n-- \n{} {unsubscribe_url}\n", strings.unsubscribe)
+}
@@ -0,0 +1,42 @@
+use super::Strings;
+use crate::db::notification::kind::Kind as NotificationKind;
+use crate::db::post::status::Status as PostStatus;
+
+pub const STRINGS: Strings = Strings {
+    notification,
+    status,
+    by: "від",
+    digest_subject: "Ваш щотижневий дайджест kotoba",
+    followed_user_posts: "Нові дописи людей, за якими ви стежите",
+    followed_tag_posts: "Нові дописи з теґами, за якими ви стежите",
+    translations: "Переклади ваших дописів",
+    unsubscribe: "Відписатися від цих листів:",
+};
+
+fn notification(kind: NotificationKind, actor: &str) -> String {
+    match kind {
+        NotificationKind::Mention => format!("@{actor} згадує вас"),
+        NotificationKind::Reply => format!("@{actor} відповідає на ваш коментар"),
+        NotificationKind::Follow => format!("@{actor} тепер стежить за вами"),
+        NotificationKind::TranslationSubmitted => {
+            format!("@{actor} надсилає переклад вашого допису")
+        }
+        NotificationKind::TranslationApproved => format!("@{actor} схвалює ваш переклад"),
+        NotificationKind::TranslationDenied => format!("@{actor} відхиляє ваш переклад"),
+        NotificationKind::ChangesRequested => {
+            format!("@{actor} просить змінити ваш переклад")
+        }
+        NotificationKind::TranslationRequestFulfilled => {
+            format!("@{actor} перекладає допис, який ви запитували")
+        }
+    }
+}
+
+fn status(status: PostStatus) -> &'static str {
+    match status {
+        PostStatus::Pending => "очікує",
+        PostStatus::Approved => "схвалено",
+        PostStatus::Denied => "відхилено",
+        PostStatus::ChangesRequested => "потрібні зміни",
+    }
+}
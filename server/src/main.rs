@@ -1,13 +1,25 @@
 use actix_cors::Cors;
 use actix_web::{get, web, App, HttpServer, Responder};
+use db::reaction::set::Set as ReactionSet;
 use dotenv::dotenv;
 use jwt_simple::prelude::HS256Key;
+use language_detection::Mode as LanguageDetectionMode;
+use machine_translation::Translator;
+use realtime::Broker;
 use sqlx::mysql::{MySqlPool, MySqlPoolOptions};
+use std::sync::Arc;
 
+mod accept_language;
 mod db;
 mod error;
+mod jobs;
 mod jwt;
+mod language_detection;
+mod machine_translation;
+mod mail;
+mod realtime;
 mod services;
+mod text;
 
 async fn index() -> actix_web::Result<actix_files::NamedFile> {
     let file = if cfg!(debug_assertions) {
@@ -22,6 +34,10 @@ async fn index() -> actix_web::Result<actix_files::NamedFile> {
 pub struct State {
     db_pool: MySqlPool,
     jwt_private_key: HS256Key,
+    translator: Option<Arc<dyn Translator>>,
+    language_detection: LanguageDetectionMode,
+    broker: Arc<dyn Broker>,
+    reactions: ReactionSet,
 }
 
 #[tokio::main]
@@ -39,6 +55,15 @@ async fn main() -> std::io::Result<()> {
 
     let jwt_private_key = HS256Key::generate();
 
+    let translator = machine_translation::from_env();
+    let language_detection = LanguageDetectionMode::from_env();
+    let broker = realtime::from_env(&db_pool);
+    let reactions = ReactionSet::from_env();
+
+    let mailer = mail::from_env();
+
+    jobs::spawn(&db_pool, mailer);
+
     HttpServer::new(move || {
         let cors = Cors::default()
             .allow_any_origin()
@@ -50,6 +75,10 @@ async fn main() -> std::io::Result<()> {
             .app_data(web::Data::new(State {
                 db_pool: db_pool.clone(),
                 jwt_private_key: jwt_private_key.clone(),
+                translator: translator.clone(),
+                language_detection,
+                broker: broker.clone(),
+                reactions: reactions.clone(),
             }))
             .service(services::sign_up::service)
             .service(services::sign_in::service)
@@ -57,24 +86,78 @@ async fn main() -> std::io::Result<()> {
             .service(services::post::all::service)
             .service(services::post::feed::service)
             .service(services::post::fetch::service)
+            .service(services::post::parallel::service)
             .service(services::post::comments::fetch::service)
+            .service(services::post::comments::thread::service)
+            .service(services::post::comments::replies::service)
             .service(services::post::comments::create::service)
+            .service(services::post::comments::edit::service)
+            .service(services::post::comments::reactions::service)
+            .service(services::post::comments::delete::service)
+            .service(services::post::comments::revisions::service)
             .service(services::reauth::service)
             .service(services::user::fetch::service)
             .service(services::user::posts::service)
             .service(services::user::comments::service)
             .service(services::languages::fetch::service)
+            .service(services::languages::create::service)
+            .service(services::languages::update::service)
             .service(services::user::search::service)
             .service(services::post::bookmarks::service)
+            .service(services::post::reactions::service)
+            .service(services::reactions::service)
             .service(services::user::follows::service)
             .service(services::user::follows::fetch::service)
             .service(services::user::bookmarks::service)
             .service(services::post::edit::service)
             .service(services::post::edit::languages::service)
             .service(services::post::translate::languages::service)
+            .service(services::post::translate::claim::service)
+            .service(services::post::translate::release::service)
+            .service(services::post::translate::draft::service)
+            .service(services::post::translate::memory::service)
             .service(services::post::translate::service)
+            .service(services::post::translation_requests::queue::service)
+            .service(services::post::translation_requests::service)
             .service(services::user::translations::service)
+            .service(services::user::translation_requests::service)
             .service(services::post::translations::status::service)
+            .service(services::post::delete::service)
+            .service(services::post::restore::service)
+            .service(services::post::translations::delete::service)
+            .service(services::post::translations::restore::service)
+            .service(services::post::revisions::service)
+            .service(services::post::revisions::diff::service)
+            .service(services::post::revisions::rollback::service)
+            .service(services::post::translations::outdated::service)
+            .service(services::post::translations::outdated::resolve::service)
+            .service(services::post::translations::reviews::fetch::service)
+            .service(services::post::translations::reviews::create::service)
+            .service(services::post::translations::suggestions::fetch::service)
+            .service(services::post::translations::suggestions::create::service)
+            .service(services::post::translations::suggestions::accept::service)
+            .service(services::post::translations::suggestions::reject::service)
+            .service(services::post::translations::vote::service)
+            .service(services::user::preferences::source_language::service)
+            .service(services::user::preferences::digest::service)
+            .service(services::unsubscribe::confirm::service)
+            .service(services::unsubscribe::service)
+            .service(services::webhooks::create::service)
+            .service(services::webhooks::fetch::service)
+            .service(services::webhooks::delete::service)
+            .service(services::webhooks::deliveries::service)
+            .service(services::webhooks::test::service)
+            .service(services::notifications::fetch::service)
+            .service(services::notifications::unread_count::service)
+            .service(services::notifications::read::service)
+            .service(services::notifications::read_all::service)
+            .service(services::notifications::preferences::service)
+            .service(services::notifications::preferences::fetch::service)
+            .service(services::notifications::preferences::email::service)
+            .service(services::stream::service)
+            .service(services::glossary::fetch::service)
+            .service(services::glossary::create::service)
+            .service(services::glossary::delete::service)
             .service(if cfg!(debug_assertions) {
                 actix_files::Files::new("/dist", "../web/public/dist")
             } else {
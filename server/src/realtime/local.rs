@@ -0,0 +1,31 @@
+use super::{Broker, Event, CHANNEL_CAPACITY};
+use futures::future::{BoxFuture, FutureExt};
+use tokio::sync::broadcast;
+
+// Everything stays within the process, enough for a single instance
+pub struct Local {
+    sender: broadcast::Sender<Event>,
+}
+
+impl Local {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+
+        Self { sender }
+    }
+}
+
+impl Broker for Local {
+    fn publish(&self, events: Vec<Event>) -> BoxFuture<'_, ()> {
+        for event in events {
+            // Nobody listening is not an error
+            let _ = self.sender.send(event);
+        }
+
+        async {}.boxed()
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.sender.subscribe()
+    }
+}
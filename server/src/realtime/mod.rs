@@ -0,0 +1,77 @@
+use crate::db::id::Id;
+use crate::db::notification::kind::Kind as NotificationKind;
+use crate::db::post::status::Status as PostStatus;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use sqlx::mysql::MySqlPool;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+pub mod local;
+use local::Local;
+
+pub mod mysql;
+use mysql::MySql;
+
+// How many events a slow subscriber can fall behind before it starts missing them
+pub const CHANNEL_CAPACITY: usize = 1024;
+
+// Events only carry ids, clients fetch whatever they need to show
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "tag")]
+pub enum Event {
+    Notification {
+        user_id: Id,
+        notification_id: Id,
+        kind: NotificationKind,
+    },
+    Comment {
+        post_content_id: Id,
+        comment_id: Id,
+    },
+    TranslationStatus {
+        post_content_id: Id,
+        translated_by: Id,
+        status: PostStatus,
+    },
+}
+
+impl Event {
+    // `user_id` is who is listening, if they are signed in,
+    // `post_content_id` is what they are looking at, if anything
+    pub fn is_for(&self, user_id: Option<Id>, post_content_id: Option<Id>) -> bool {
+        match self {
+            Self::Notification { user_id: to, .. } => user_id == Some(*to),
+            Self::Comment {
+                post_content_id: on,
+                ..
+            } => post_content_id == Some(*on),
+            Self::TranslationStatus {
+                post_content_id: on,
+                translated_by,
+                ..
+            } => post_content_id == Some(*on) || user_id == Some(*translated_by),
+        }
+    }
+}
+
+// Fans events out to every subscriber, on every server instance
+// if the implementation supports it. Events are published once the
+// transaction that caused them has been committed, and publishing
+// never fails the request, the data is already in place by then
+pub trait Broker: Send + Sync {
+    fn publish(&self, events: Vec<Event>) -> BoxFuture<'_, ()>;
+
+    fn subscribe(&self) -> broadcast::Receiver<Event>;
+}
+
+// Single node `local` broker is used if `$REALTIME_BROKER` is not set
+pub fn from_env(db_pool: &MySqlPool) -> Arc<dyn Broker> {
+    let broker = std::env::var("REALTIME_BROKER").unwrap_or_else(|_| "local".to_owned());
+
+    match broker.as_str() {
+        "local" => Arc::new(Local::new()),
+        "mysql" => Arc::new(MySql::new(db_pool.clone())),
+        _ => panic!("Unknown $REALTIME_BROKER `{broker}`"),
+    }
+}
@@ -0,0 +1,166 @@
+use super::{Broker, Event, CHANNEL_CAPACITY};
+use crate::db::id::Id;
+use futures::future::{BoxFuture, FutureExt};
+use sqlx::mysql::MySqlPool;
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+// Ids are allocated on insert but become visible on commit, so a lower id
+// can show up after a higher one was already polled. Rows this recent are
+// read again on every poll and deduplicated by id
+const REREAD_WINDOW_SECS: i64 = 5;
+
+// Events go through the `realtime_events` table, every instance
+// polls it and forwards the new rows to its own subscribers.
+// Old rows are cleaned up by the purge job
+pub struct MySql {
+    db_pool: MySqlPool,
+    sender: broadcast::Sender<Event>,
+}
+
+impl MySql {
+    pub fn new(db_pool: MySqlPool) -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+
+        tokio::spawn(poll(db_pool.clone(), sender.clone()));
+
+        Self { db_pool, sender }
+    }
+}
+
+impl Broker for MySql {
+    fn publish(&self, events: Vec<Event>) -> BoxFuture<'_, ()> {
+        async move {
+            for event in events {
+                if let Err(error) = insert_event(&self.db_pool, &event).await {
+                    eprintln!("Failed to publish realtime event: {error}");
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.sender.subscribe()
+    }
+}
+
+async fn poll(db_pool: MySqlPool, sender: broadcast::Sender<Event>) {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    // Only what gets published from now on
+    let (mut last_event_id, mut seen_event_ids) = loop {
+        match skip_published_events(&db_pool).await {
+            Ok(cursor) => break cursor,
+            Err(error) => eprintln!("Failed to fetch the last realtime event: {error}"),
+        }
+
+        interval.tick().await;
+    };
+
+    loop {
+        interval.tick().await;
+
+        let rows = match fetch_events_after(&db_pool, last_event_id).await {
+            Ok(rows) => rows,
+            Err(error) => {
+                eprintln!("Failed to fetch realtime events: {error}");
+                continue;
+            }
+        };
+
+        let mut fetched_event_ids = HashSet::new();
+        for (id, payload) in rows {
+            fetched_event_ids.insert(id);
+            last_event_id = last_event_id.max(id);
+
+            if seen_event_ids.contains(&id) {
+                continue;
+            }
+
+            match serde_json::from_str::<Event>(&payload) {
+                Ok(event) => {
+                    let _ = sender.send(event);
+                }
+                Err(error) => eprintln!("Failed to parse realtime event {id}: {error}"),
+            }
+        }
+
+        // Rows that left the window are never fetched again
+        seen_event_ids = fetched_event_ids;
+    }
+}
+
+async fn skip_published_events(db_pool: &MySqlPool) -> Result<(Id, HashSet<Id>), sqlx::Error> {
+    let last_event_id = fetch_last_event_id(db_pool).await?;
+    let seen_event_ids = fetch_events_after(db_pool, last_event_id)
+        .await?
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+
+    Ok((last_event_id, seen_event_ids))
+}
+
+async fn insert_event(db_pool: &MySqlPool, event: &Event) -> Result<(), sqlx::Error> {
+    let payload = serde_json::to_string(event)
+        .expect("`Event` only holds ids and enums, it always serializes");
+
+    sqlx::query!(
+        "
+        INSERT INTO realtime_events
+        (
+            payload,
+            created_at
+        )
+        VALUES
+        (
+            ?,
+            NOW()
+        )
+        ",
+        payload
+    )
+    .execute(db_pool)
+    .await?;
+
+    Ok(())
+}
+
+async fn fetch_last_event_id(db_pool: &MySqlPool) -> Result<Id, sqlx::Error> {
+    sqlx::query!(
+        "
+        SELECT
+            COALESCE(MAX(id), 0) AS `last_event_id!`
+        FROM realtime_events
+        "
+    )
+    .fetch_one(db_pool)
+    .await
+    .map(|row| row.last_event_id as Id)
+}
+
+async fn fetch_events_after(
+    db_pool: &MySqlPool,
+    last_event_id: Id,
+) -> Result<Vec<(Id, String)>, sqlx::Error> {
+    sqlx::query!(
+        "
+        SELECT
+            id,
+            payload
+        FROM realtime_events
+        WHERE
+            id > ?
+            OR created_at >= NOW() - INTERVAL ? SECOND
+        ORDER BY id
+        ",
+        last_event_id,
+        REREAD_WINDOW_SECS
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|rows| rows.into_iter().map(|row| (row.id, row.payload)).collect())
+}
@@ -0,0 +1,109 @@
+use crate::db::glossary::scope::Scope;
+use crate::db::glossary::term::Term;
+use crate::db::id::Id;
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::{Result, Transaction};
+
+pub async fn fetch_posted_by(db_pool: &MySqlPool, post_id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            posted_by
+        FROM posts
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        post_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.posted_by))
+}
+
+pub async fn language_exists(db_pool: &MySqlPool, language_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM languages
+        WHERE id = ?
+        ",
+        language_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn insert_term(
+    tx: &mut Transaction<'_, MySql>,
+    scope: Scope,
+    post_id: Option<Id>,
+    author_id: Option<Id>,
+    language_id: Id,
+    term: &Term,
+    translations: &[(Id, Term)],
+    created_by: Id,
+) -> Result<Id> {
+    let glossary_term_id = sqlx::query!(
+        "
+        INSERT INTO glossary_terms
+        (
+            scope,
+            post_id,
+            author_id,
+            language_id,
+            term,
+            created_by,
+            created_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        scope.as_str(),
+        post_id,
+        author_id,
+        language_id,
+        term.as_ref(),
+        created_by
+    )
+    .execute(&mut *tx)
+    .await?
+    .last_insert_id() as Id;
+
+    // TODO: Batch this up!
+    for (language_id, translation) in translations {
+        sqlx::query!(
+            "
+            INSERT INTO glossary_term_translations
+            (
+                glossary_term_id,
+                language_id,
+                translation
+            )
+            VALUES
+            (
+                ?,
+                ?,
+                ?
+            )
+            ",
+            glossary_term_id,
+            language_id,
+            translation.as_ref()
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    Ok(glossary_term_id)
+}
@@ -0,0 +1,134 @@
+use crate::db::glossary::scope::Scope as GlossaryScope;
+use crate::db::glossary::term::Term;
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+mod db;
+use db::{fetch_posted_by, insert_term, language_exists};
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "tag")]
+pub enum Scope {
+    Post { post_id: Id },
+    // Applies to every post of the user creating the term
+    Author,
+    Site,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TermTranslation {
+    pub language_id: Id,
+    pub translation: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub scope: Scope,
+    pub language_id: Id,
+    pub term: String,
+    pub translations: Vec<TermTranslation>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { glossary_term_id: Id },
+}
+
+#[post("/api/glossary/create")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_manage_glossaries() {
+        return Err(Error::Validation);
+    }
+
+    let (scope, post_id, author_id) = match request.scope {
+        Scope::Post { post_id } => {
+            let posted_by = match fetch_posted_by(&state.db_pool, post_id).await? {
+                Some(posted_by) => posted_by,
+                None => return Err(Error::Validation),
+            };
+
+            if posted_by != custom_claims.id && !custom_claims.role.can_moderate() {
+                return Err(Error::Validation);
+            }
+
+            (GlossaryScope::Post, Some(post_id), None)
+        }
+        Scope::Author => (GlossaryScope::Author, None, Some(custom_claims.id)),
+        Scope::Site => {
+            if !custom_claims.role.can_moderate() {
+                return Err(Error::Validation);
+            }
+
+            (GlossaryScope::Site, None, None)
+        }
+    };
+
+    let term = Term::parse(request.term)?;
+
+    if request.translations.is_empty() {
+        return Err(Error::Validation);
+    }
+
+    let mut language_ids = HashSet::new();
+    let mut translations = Vec::new();
+    for translation in request.translations {
+        if translation.language_id == request.language_id
+            || !language_ids.insert(translation.language_id)
+        {
+            return Err(Error::Validation);
+        }
+
+        translations.push((
+            translation.language_id,
+            Term::parse(translation.translation)?,
+        ));
+    }
+
+    // Duplicates were rejected above, so this is at most one query per language
+    for language_id in language_ids.iter().chain([&request.language_id]) {
+        if !language_exists(&state.db_pool, *language_id).await? {
+            return Err(Error::Validation);
+        }
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let glossary_term_id = insert_term(
+        &mut tx,
+        scope,
+        post_id,
+        author_id,
+        request.language_id,
+        &term,
+        &translations,
+        custom_claims.id,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { glossary_term_id }))
+}
@@ -0,0 +1,42 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::{Result, Transaction};
+
+pub async fn fetch_created_by(db_pool: &MySqlPool, glossary_term_id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            created_by
+        FROM glossary_terms
+        WHERE id = ?
+        ",
+        glossary_term_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.created_by))
+}
+
+pub async fn delete_term(tx: &mut Transaction<'_, MySql>, glossary_term_id: Id) -> Result<()> {
+    sqlx::query!(
+        "
+        DELETE FROM glossary_term_translations
+        WHERE glossary_term_id = ?
+        ",
+        glossary_term_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM glossary_terms
+        WHERE id = ?
+        ",
+        glossary_term_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
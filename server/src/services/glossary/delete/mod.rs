@@ -0,0 +1,57 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{delete_term, fetch_created_by};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub glossary_term_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success,
+}
+
+#[post("/api/glossary/delete")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let created_by = match fetch_created_by(&state.db_pool, request.glossary_term_id).await? {
+        Some(created_by) => created_by,
+        None => return Err(Error::Validation),
+    };
+
+    if created_by != custom_claims.id && !custom_claims.role.can_moderate() {
+        return Err(Error::Validation);
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    delete_term(&mut tx, request.glossary_term_id).await?;
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
@@ -0,0 +1,28 @@
+use crate::db::glossary::Entry;
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub post_id: Id,
+    // The language the post is being translated into
+    pub language_id: Id,
+}
+
+#[post("/api/glossary")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let entries =
+        Entry::fetch_applicable(&state.db_pool, request.post_id, request.language_id).await?;
+
+    Ok(HttpResponse::Ok().json(entries))
+}
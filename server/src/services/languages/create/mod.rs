@@ -0,0 +1,83 @@
+use crate::db::id::Id;
+use crate::db::language::code::Code;
+use crate::db::language::direction::Direction;
+use crate::db::language::name::Name;
+use crate::db::language::script::Script;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{insert_language, language_exists, parent_exists};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub script: Option<String>,
+    pub direction: Direction,
+    pub parent_id: Option<Id>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    AlreadyExists,
+    Success { id: Id },
+}
+
+#[post("/api/languages/create")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_manage_languages() {
+        return Err(Error::Validation);
+    }
+
+    let code = Code::parse(request.code)?;
+    let name = Name::parse(request.name)?;
+    let native_name = Name::parse(request.native_name)?;
+    let script = request.script.map(Script::parse).transpose()?;
+
+    if let Some(parent_id) = request.parent_id {
+        if !parent_exists(&state.db_pool, parent_id).await? {
+            return Err(Error::Validation);
+        }
+    }
+
+    if language_exists(&state.db_pool, &code, &name).await? {
+        return Ok(HttpResponse::Ok().json(Response::AlreadyExists));
+    }
+
+    let id = insert_language(
+        &state.db_pool,
+        &code,
+        &name,
+        &native_name,
+        script.as_ref(),
+        request.direction,
+        request.parent_id,
+    )
+    .await?
+    .last_insert_id() as Id;
+
+    Ok(HttpResponse::Ok().json(Response::Success { id }))
+}
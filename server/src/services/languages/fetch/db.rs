@@ -1,4 +1,4 @@
-use crate::db::language::Language;
+use crate::db::language::{Language, RawLanguage};
 use sqlx::mysql::MySqlPool;
 use sqlx::Result;
 
@@ -8,18 +8,29 @@ pub async fn find(db_pool: &MySqlPool, query: &str) -> Result<Vec<Language>> {
     let query = format!("%{}%", query);
 
     sqlx::query_as!(
-        Language,
+        RawLanguage,
         "
             SELECT
                 id,
-                name
+                code,
+                name,
+                native_name,
+                script,
+                direction,
+                parent_id
             FROM languages
-            WHERE name LIKE ?
+            WHERE
+                name LIKE ?
+                OR native_name LIKE ?
+                OR code LIKE ?
             LIMIT ?
             ",
         query,
+        query,
+        query,
         SUGGESTIONS_MAX_AMOUNT
     )
     .fetch_all(db_pool)
     .await
+    .map(|result| result.into_iter().map(|language| language.into()).collect())
 }
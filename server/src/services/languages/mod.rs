@@ -1 +1,3 @@
+pub mod create;
 pub mod fetch;
+pub mod update;
@@ -0,0 +1,81 @@
+use crate::db::id::Id;
+use crate::db::language::code::Code;
+use crate::db::language::direction::Direction;
+use crate::db::language::name::Name;
+use crate::db::language::script::Script;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn language_exists(db_pool: &MySqlPool, language_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM languages
+        WHERE id = ?
+        ",
+        language_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn other_language_exists(
+    db_pool: &MySqlPool,
+    language_id: Id,
+    code: &Code,
+    name: &Name,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM languages
+        WHERE
+            id != ?
+            AND (code = ? OR name = ?)
+        LIMIT 1
+        ",
+        language_id,
+        code.as_ref(),
+        name.as_ref()
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn update_language(
+    db_pool: &MySqlPool,
+    language_id: Id,
+    code: &Code,
+    name: &Name,
+    native_name: &Name,
+    script: Option<&Script>,
+    direction: Direction,
+    parent_id: Option<Id>,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE languages
+        SET
+            code = ?,
+            name = ?,
+            native_name = ?,
+            script = ?,
+            direction = ?,
+            parent_id = ?
+        WHERE id = ?
+        ",
+        code.as_ref(),
+        name.as_ref(),
+        native_name.as_ref(),
+        script.map(|script| script.as_ref()),
+        direction.as_str(),
+        parent_id,
+        language_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,100 @@
+use crate::db::id::Id;
+use crate::db::language::code::Code;
+use crate::db::language::direction::Direction;
+use crate::db::language::fetch_ancestor_ids;
+use crate::db::language::name::Name;
+use crate::db::language::script::Script;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{language_exists, other_language_exists, update_language};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub language_id: Id,
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub script: Option<String>,
+    pub direction: Direction,
+    pub parent_id: Option<Id>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    NotFound,
+    AlreadyExists,
+    // The parent would end up falling back to the language itself
+    InvalidParent,
+    Success,
+}
+
+#[post("/api/languages/update")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_manage_languages() {
+        return Err(Error::Validation);
+    }
+
+    let code = Code::parse(request.code)?;
+    let name = Name::parse(request.name)?;
+    let native_name = Name::parse(request.native_name)?;
+    let script = request.script.map(Script::parse).transpose()?;
+
+    if !language_exists(&state.db_pool, request.language_id).await? {
+        return Ok(HttpResponse::Ok().json(Response::NotFound));
+    }
+
+    if let Some(parent_id) = request.parent_id {
+        if !language_exists(&state.db_pool, parent_id).await? {
+            return Err(Error::Validation);
+        }
+
+        if parent_id == request.language_id
+            || fetch_ancestor_ids(&state.db_pool, parent_id)
+                .await?
+                .contains(&request.language_id)
+        {
+            return Ok(HttpResponse::Ok().json(Response::InvalidParent));
+        }
+    }
+
+    if other_language_exists(&state.db_pool, request.language_id, &code, &name).await? {
+        return Ok(HttpResponse::Ok().json(Response::AlreadyExists));
+    }
+
+    update_language(
+        &state.db_pool,
+        request.language_id,
+        &code,
+        &name,
+        &native_name,
+        script.as_ref(),
+        request.direction,
+        request.parent_id,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
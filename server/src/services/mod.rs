@@ -1,6 +1,12 @@
+pub mod glossary;
 pub mod languages;
+pub mod notifications;
 pub mod post;
+pub mod reactions;
 pub mod reauth;
 pub mod sign_in;
 pub mod sign_up;
+pub mod stream;
+pub mod unsubscribe;
 pub mod user;
+pub mod webhooks;
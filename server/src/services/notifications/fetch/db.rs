@@ -0,0 +1,98 @@
+use crate::db::id::Id;
+use crate::db::notification::kind::Kind;
+use crate::db::page::Page;
+use crate::db::user::meta::Meta as UserMeta;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct Notification {
+    pub id: Id,
+    pub kind: Kind,
+    pub actor: UserMeta,
+    // Set for everything but follows
+    pub post_id: Option<Id>,
+    pub post_content_id: Option<Id>,
+    pub title: Option<String>,
+    // Set for mentions in comments and replies
+    pub comment_id: Option<Id>,
+    pub created_at: i64,
+    pub is_read: bool,
+}
+
+impl From<RawNotification> for Notification {
+    fn from(raw: RawNotification) -> Self {
+        Self {
+            id: raw.id,
+            // TODO: Better error handling
+            kind: Kind::from_str(&raw.kind).unwrap(),
+            actor: UserMeta {
+                id: raw.actor_id,
+                handle: raw.actor_handle,
+                profile_picture_file_name: raw.actor_profile_picture_file_name,
+            },
+            post_id: raw.post_id,
+            post_content_id: raw.post_content_id,
+            title: raw.title,
+            comment_id: raw.comment_id,
+            created_at: raw.created_at.timestamp_millis(),
+            is_read: raw.read_at.is_some(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawNotification {
+    id: Id,
+    kind: String,
+    actor_id: Id,
+    actor_handle: String,
+    actor_profile_picture_file_name: Option<String>,
+    post_id: Option<Id>,
+    post_content_id: Option<Id>,
+    title: Option<String>,
+    comment_id: Option<Id>,
+    created_at: NaiveDateTime,
+    read_at: Option<NaiveDateTime>,
+}
+
+// Newest first, read ones included
+pub async fn fetch_page(db_pool: &MySqlPool, user_id: Id, page: Page) -> Result<Vec<Notification>> {
+    sqlx::query_as!(
+        RawNotification,
+        "
+        SELECT
+            notifications.id,
+            notifications.kind,
+            users.id AS actor_id,
+            users.handle AS actor_handle,
+            CONCAT(files.id, \".\", files.extension) AS `actor_profile_picture_file_name?`,
+            post_contents.post_id AS `post_id?`,
+            notifications.post_content_id,
+            post_contents.title AS `title?`,
+            notifications.comment_id,
+            notifications.created_at,
+            notifications.read_at
+        FROM notifications
+            JOIN users ON notifications.actor_id = users.id
+            LEFT JOIN files ON users.profile_picture_file_id = files.id
+            LEFT JOIN post_contents ON notifications.post_content_id = post_contents.id
+        WHERE notifications.user_id = ?
+        ORDER BY notifications.created_at DESC, notifications.id DESC
+        LIMIT ? OFFSET ?
+        ",
+        user_id,
+        page.get_limit(),
+        page.get_offset()
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|result| {
+        result
+            .into_iter()
+            .map(|notification| notification.into())
+            .collect()
+    })
+}
@@ -0,0 +1,5 @@
+pub mod fetch;
+pub mod preferences;
+pub mod read;
+pub mod read_all;
+pub mod unread_count;
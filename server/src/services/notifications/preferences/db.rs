@@ -0,0 +1,69 @@
+use crate::db::id::Id;
+use crate::db::notification::kind::Kind;
+use sqlx::mysql::{MySql, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+pub async fn update_preference(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    kind: Kind,
+    is_enabled: bool,
+) -> Result<MySqlQueryResult> {
+    if preference_exists(tx, user_id, kind).await? {
+        sqlx::query!(
+            "
+            UPDATE notification_preferences
+            SET
+                is_enabled = ?
+            WHERE user_id = ? AND kind = ?
+            ",
+            is_enabled,
+            user_id,
+            kind.as_str()
+        )
+        .execute(&mut *tx)
+        .await
+    } else {
+        sqlx::query!(
+            "
+            INSERT INTO notification_preferences
+            (
+                user_id,
+                kind,
+                is_enabled
+            )
+            VALUES
+            (
+                ?,
+                ?,
+                ?
+            )
+            ",
+            user_id,
+            kind.as_str(),
+            is_enabled
+        )
+        .execute(&mut *tx)
+        .await
+    }
+}
+
+async fn preference_exists(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    kind: Kind,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM notification_preferences
+        WHERE user_id = ? AND kind = ?
+        ",
+        user_id,
+        kind.as_str()
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.is_some())
+}
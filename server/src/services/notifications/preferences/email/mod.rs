@@ -0,0 +1,49 @@
+use crate::db::notification::kind::Kind;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::update_email_preference;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub kind: Kind,
+    pub is_emailed: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success,
+}
+
+#[post("/api/notifications/preferences/email")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let mut tx = state.db_pool.begin().await?;
+
+    update_email_preference(&mut tx, custom_claims.id, request.kind, request.is_emailed).await?;
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
@@ -0,0 +1,50 @@
+use crate::db::id::Id;
+use crate::db::notification::kind::Kind;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize)]
+pub struct Preference {
+    pub kind: Kind,
+    pub is_enabled: bool,
+    pub is_emailed: bool,
+}
+
+// Every kind is listed, the ones never touched
+// are enabled in-app and not emailed
+pub async fn fetch_preferences(db_pool: &MySqlPool, user_id: Id) -> Result<Vec<Preference>> {
+    let stored: HashMap<String, (bool, bool)> = sqlx::query!(
+        "
+        SELECT
+            kind,
+            is_enabled,
+            is_emailed
+        FROM notification_preferences
+        WHERE user_id = ?
+        ",
+        user_id
+    )
+    .fetch_all(db_pool)
+    .await?
+    .into_iter()
+    .map(|row| (row.kind, (row.is_enabled != 0, row.is_emailed != 0)))
+    .collect();
+
+    let preferences = Kind::ALL
+        .iter()
+        .map(|kind| {
+            let (is_enabled, is_emailed) =
+                stored.get(kind.as_str()).copied().unwrap_or((true, false));
+
+            Preference {
+                kind: *kind,
+                is_enabled,
+                is_emailed,
+            }
+        })
+        .collect();
+
+    Ok(preferences)
+}
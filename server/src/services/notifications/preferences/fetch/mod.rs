@@ -0,0 +1,41 @@
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{fetch_preferences, Preference};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { preferences: Vec<Preference> },
+}
+
+#[post("/api/notifications/preferences/fetch")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let preferences = fetch_preferences(&state.db_pool, custom_claims.id).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { preferences }))
+}
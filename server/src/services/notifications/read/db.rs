@@ -0,0 +1,26 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+// Notifications of other users are silently left alone
+pub async fn mark_as_read(
+    db_pool: &MySqlPool,
+    notification_id: Id,
+    user_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE notifications
+        SET
+            read_at = NOW()
+        WHERE
+            id = ?
+            AND user_id = ?
+            AND read_at IS NULL
+        ",
+        notification_id,
+        user_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,19 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn mark_all_as_read(db_pool: &MySqlPool, user_id: Id) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE notifications
+        SET
+            read_at = NOW()
+        WHERE
+            user_id = ?
+            AND read_at IS NULL
+        ",
+        user_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,20 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+pub async fn fetch_unread_count(db_pool: &MySqlPool, user_id: Id) -> Result<i64> {
+    sqlx::query!(
+        "
+        SELECT
+            COUNT(*) AS `unread_count!`
+        FROM notifications
+        WHERE
+            user_id = ?
+            AND read_at IS NULL
+        ",
+        user_id
+    )
+    .fetch_one(db_pool)
+    .await
+    .map(|row| row.unread_count)
+}
@@ -2,51 +2,109 @@ use crate::db::id::{Id, IdRow};
 use crate::db::page::Page;
 use crate::db::post::meta::Meta as PostMeta;
 use crate::db::post::status::Status;
+use serde::Deserialize;
 use sqlx::mysql::MySqlPool;
 use sqlx::Result;
 
+#[derive(Debug, Copy, Clone, Default, Deserialize)]
+#[serde(tag = "tag")]
+pub enum Sort {
+    #[default]
+    Newest,
+    // Reactions on the post, no matter the translation
+    MostReacted,
+}
+
 pub async fn fetch_page(
     db_pool: &MySqlPool,
+    fetcher_user_id: Option<Id>,
     fetcher_language_ids: &Vec<Id>,
+    prefers_source_language: bool,
     query: &str,
+    sort: Sort,
     page: Page,
 ) -> Result<Vec<PostMeta>> {
     let query = format!("%{}%", query);
 
-    let post_ids = sqlx::query_as!(
-        IdRow,
-        "
-        SELECT DISTINCT
-            filtered_posts.id
-        FROM
-        (
-            SELECT posts.id FROM posts
-                JOIN post_contents ON posts.id = post_contents.post_id
-                JOIN users ON posts.posted_by = users.id
-                LEFT JOIN files ON users.profile_picture_file_id = files.id
+    let post_ids: Vec<Id> = match sort {
+        Sort::Newest => sqlx::query_as!(
+            IdRow,
+            "
+            SELECT DISTINCT
+                filtered_posts.id
+            FROM
+            (
+                SELECT posts.id FROM posts
+                    JOIN post_contents ON posts.id = post_contents.post_id
+                    JOIN users ON posts.posted_by = users.id
+                    LEFT JOIN files ON users.profile_picture_file_id = files.id
+                WHERE
+                    status = ?
+                    AND (title LIKE ? OR post_contents.description LIKE ? OR content LIKE ?)
+                    AND posts.deleted_at IS NULL
+                    AND post_contents.deleted_at IS NULL
+                ORDER BY translated_at DESC
+                LIMIT ? OFFSET ?
+            ) AS filtered_posts
+            ",
+            Status::Approved.as_str(),
+            query,
+            query,
+            query,
+            page.get_limit(),
+            page.get_offset(),
+        )
+        .fetch_all(db_pool)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect(),
+        // One row per post, so the count isn't multiplied by its translations
+        Sort::MostReacted => sqlx::query_as!(
+            IdRow,
+            "
+            SELECT
+                posts.id
+            FROM posts
+                LEFT JOIN reactions ON posts.id = reactions.post_id
             WHERE
-                status = ?
-                AND (title LIKE ? OR post_contents.description LIKE ? OR content LIKE ?)
-            ORDER BY translated_at DESC
+                posts.deleted_at IS NULL
+                AND EXISTS (
+                    SELECT
+                        id
+                    FROM post_contents
+                    WHERE
+                        post_id = posts.id
+                        AND status = ?
+                        AND (title LIKE ? OR description LIKE ? OR content LIKE ?)
+                        AND deleted_at IS NULL
+                )
+            GROUP BY posts.id
+            ORDER BY COUNT(reactions.id) DESC, posts.id DESC
             LIMIT ? OFFSET ?
-        ) AS filtered_posts
-        ",
-        Status::Approved.as_str(),
-        query,
-        query,
-        query,
-        page.get_limit(),
-        page.get_offset(),
-    )
-    .fetch_all(db_pool)
-    .await?
-    .into_iter()
-    .map(|row| row.id)
-    .collect();
+            ",
+            Status::Approved.as_str(),
+            query,
+            query,
+            query,
+            page.get_limit(),
+            page.get_offset(),
+        )
+        .fetch_all(db_pool)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect(),
+    };
 
-    let page =
-        PostMeta::fetch_from_post_ids_with_best_language(db_pool, fetcher_language_ids, &post_ids)
-            .await?;
+    let page = PostMeta::fetch_from_post_ids_with_best_language(
+        db_pool,
+        fetcher_user_id,
+        fetcher_language_ids,
+        prefers_source_language,
+        &post_ids,
+    )
+    .await?;
 
     Ok(page)
 }
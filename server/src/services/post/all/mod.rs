@@ -1,25 +1,29 @@
+use crate::accept_language;
 use crate::db::id::Id;
 use crate::db::language::fetch_user_language_ids;
 use crate::db::page::Page;
 use crate::db::post::meta::Meta as PostMeta;
+use crate::db::user::fetch_prefers_source_language;
 use crate::error::Error;
 use crate::jwt;
 use crate::State;
 use actix_web::{
     post,
     web::{Data, Json},
-    HttpResponse,
+    HttpRequest, HttpResponse,
 };
 use serde::{Deserialize, Serialize};
 
 mod db;
-use db::fetch_page;
+use db::{fetch_page, Sort};
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
     pub preferences: Preferences,
 
     pub query: String,
+    #[serde(default)]
+    pub sort: Sort,
     pub limit: u64,
     pub offset: u64,
 }
@@ -27,8 +31,16 @@ pub struct Request {
 #[derive(Debug, Deserialize)]
 #[serde(tag = "tag")]
 pub enum Preferences {
-    Unauthorized { language_ids: Vec<Id> },
-    Authorized { token: String },
+    Unauthorized {
+        // Taken from the `Accept-Language` header when empty
+        #[serde(default)]
+        language_ids: Vec<Id>,
+        #[serde(default)]
+        prefers_source_language: bool,
+    },
+    Authorized {
+        token: String,
+    },
 }
 
 #[derive(Debug, Serialize)]
@@ -41,23 +53,49 @@ pub enum Response {
 #[post("/api/post/all")]
 pub async fn service(
     state: Data<State>,
+    http_request: HttpRequest,
     Json(request): Json<Request>,
 ) -> Result<HttpResponse, Error> {
     let page = Page::new(request.limit, request.offset);
 
-    let fetcher_language_ids = match request.preferences {
-        Preferences::Unauthorized { language_ids } => language_ids,
+    let (fetcher_user_id, fetcher_language_ids, prefers_source_language) = match request.preferences
+    {
+        Preferences::Unauthorized {
+            language_ids,
+            prefers_source_language,
+        } => {
+            let language_ids = if language_ids.is_empty() {
+                accept_language::fetch_language_ids(&state.db_pool, &http_request).await?
+            } else {
+                language_ids
+            };
+
+            (None, language_ids, prefers_source_language)
+        }
         Preferences::Authorized { token } => {
             let fetcher_user_id = match jwt::auth(&state.jwt_private_key, &token) {
                 Ok(custom_claims) => custom_claims.id,
                 Err(_) => return Ok(HttpResponse::Ok().json(Response::Unauthorized)),
             };
 
-            fetch_user_language_ids(&state.db_pool, fetcher_user_id).await?
+            let language_ids = fetch_user_language_ids(&state.db_pool, fetcher_user_id).await?;
+            let prefers_source_language =
+                fetch_prefers_source_language(&state.db_pool, fetcher_user_id).await?;
+
+            (Some(fetcher_user_id), language_ids, prefers_source_language)
         }
     };
 
-    let page = fetch_page(&state.db_pool, &fetcher_language_ids, &request.query, page).await?;
+    let page = fetch_page(
+        &state.db_pool,
+        fetcher_user_id,
+        &fetcher_language_ids,
+        prefers_source_language,
+        &request.query,
+        request.sort,
+        page,
+    )
+    .await?;
 
     Ok(HttpResponse::Ok().json(Response::Success { page }))
 }
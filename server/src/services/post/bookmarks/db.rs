@@ -1,21 +1,21 @@
 use crate::db::id::Id;
-use sqlx::mysql::{MySqlPool, MySqlQueryResult};
-use sqlx::Result;
+use sqlx::mysql::{MySql, MySqlQueryResult};
+use sqlx::{Result, Transaction};
 
 pub async fn update_bookmark(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     user_id: Id,
     post_id: Id,
 ) -> Result<MySqlQueryResult> {
-    if bookmark_exists(db_pool, user_id, post_id).await? {
-        delete_bookmark(db_pool, user_id, post_id).await
+    if bookmark_exists(tx, user_id, post_id).await? {
+        delete_bookmark(tx, user_id, post_id).await
     } else {
-        create_bookmark(db_pool, user_id, post_id).await
+        create_bookmark(tx, user_id, post_id).await
     }
 }
 
 async fn create_bookmark(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     user_id: Id,
     post_id: Id,
 ) -> Result<MySqlQueryResult> {
@@ -35,12 +35,12 @@ async fn create_bookmark(
         user_id,
         post_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
 
 async fn delete_bookmark(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     user_id: Id,
     post_id: Id,
 ) -> Result<MySqlQueryResult> {
@@ -52,11 +52,15 @@ async fn delete_bookmark(
         user_id,
         post_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
 
-async fn bookmark_exists(db_pool: &MySqlPool, user_id: Id, post_id: Id) -> Result<bool> {
+async fn bookmark_exists(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    post_id: Id,
+) -> Result<bool> {
     sqlx::query!(
         "
         SELECT
@@ -67,7 +71,7 @@ async fn bookmark_exists(db_pool: &MySqlPool, user_id: Id, post_id: Id) -> Resul
         user_id,
         post_id
     )
-    .fetch_optional(db_pool)
+    .fetch_optional(&mut *tx)
     .await
     .map(|result| result.is_some())
 }
@@ -42,7 +42,11 @@ pub async fn service(
         return Err(Error::Validation);
     }
 
-    update_bookmark(&state.db_pool, custom_claims.id, request.post_id).await?;
+    let mut tx = state.db_pool.begin().await?;
+
+    update_bookmark(&mut tx, custom_claims.id, request.post_id).await?;
+
+    tx.commit().await?;
 
     Ok(HttpResponse::Ok().json(Response::Success))
 }
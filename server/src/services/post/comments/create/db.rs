@@ -1,11 +1,62 @@
 use crate::db::comment::content::Content;
 use crate::db::id::Id;
-use sqlx::mysql::{MySqlPool, MySqlQueryResult};
-use sqlx::Result;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
 
-pub async fn insert_comment(
+pub async fn post_content_exists(db_pool: &MySqlPool, post_content_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            post_contents.id
+        FROM posts
+            JOIN post_contents ON posts.id = post_contents.post_id
+        WHERE
+            post_contents.id = ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+        ",
+        post_content_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+// Replies can go to comments on any translation of the same post,
+// but not to deleted ones or to comments on other posts
+pub async fn is_valid_reply_target(
     db_pool: &MySqlPool,
     post_content_id: Id,
+    reply_to: Id,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            comments.id
+        FROM comments
+            JOIN post_contents ON comments.post_content_id = post_contents.id
+        WHERE
+            comments.id = ?
+            AND comments.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+            AND post_contents.post_id = (
+                SELECT
+                    post_id
+                FROM post_contents AS reply_post_contents
+                WHERE reply_post_contents.id = ?
+            )
+        ",
+        reply_to,
+        post_content_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn insert_comment(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
     reply_to: Option<Id>,
     content: &Content,
     posted_by: Id,
@@ -34,6 +85,24 @@ pub async fn insert_comment(
         content.as_ref(),
         posted_by
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
+    .await
+}
+
+pub async fn fetch_posted_by(
+    tx: &mut Transaction<'_, MySql>,
+    comment_id: Id,
+) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            posted_by
+        FROM comments
+        WHERE id = ?
+        ",
+        comment_id
+    )
+    .fetch_optional(&mut *tx)
     .await
+    .map(|result| result.map(|row| row.posted_by))
 }
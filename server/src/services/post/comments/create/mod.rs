@@ -1,7 +1,11 @@
 use crate::db::comment::content::Content;
 use crate::db::id::Id;
+use crate::db::mention::{update_mentions, Target as MentionTarget};
+use crate::db::notification::{kind::Kind as NotificationKind, notify};
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
 use crate::error::Error;
 use crate::jwt;
+use crate::realtime::Event;
 use crate::State;
 use actix_web::{
     post,
@@ -11,7 +15,7 @@ use actix_web::{
 use serde::{Deserialize, Serialize};
 
 mod db;
-use db::insert_comment;
+use db::{fetch_posted_by, insert_comment, is_valid_reply_target, post_content_exists};
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
@@ -26,6 +30,7 @@ pub struct Request {
 #[serde(tag = "tag")]
 pub enum Response {
     Unauthorized,
+    InvalidReplyTarget,
     Success,
 }
 
@@ -45,19 +50,72 @@ pub async fn service(
         return Err(Error::Validation);
     }
 
+    if !post_content_exists(&state.db_pool, request.post_content_id).await? {
+        return Err(Error::Validation);
+    }
+
+    if let Some(reply_to) = request.reply_to {
+        if !is_valid_reply_target(&state.db_pool, request.post_content_id, reply_to).await? {
+            return Ok(HttpResponse::Ok().json(Response::InvalidReplyTarget));
+        }
+    }
+
     let content = Content::parse(request.content)?;
 
     // TODO: Update related fields
     // TODO: check upload limits
 
-    insert_comment(
-        &state.db_pool,
+    let mut tx = state.db_pool.begin().await?;
+
+    let comment_id = insert_comment(
+        &mut tx,
         request.post_content_id,
         request.reply_to,
         &content,
         custom_claims.id,
     )
+    .await?
+    .last_insert_id() as Id;
+
+    let mut events = update_mentions(
+        &mut tx,
+        MentionTarget::Comment(comment_id),
+        content.as_ref(),
+        custom_claims.id,
+    )
     .await?;
 
+    if let Some(reply_to) = request.reply_to {
+        if let Some(reply_to_posted_by) = fetch_posted_by(&mut tx, reply_to).await? {
+            let event = notify(
+                &mut tx,
+                reply_to_posted_by,
+                NotificationKind::Reply,
+                custom_claims.id,
+                Some(request.post_content_id),
+                Some(comment_id),
+            )
+            .await?;
+
+            events.extend(event);
+        }
+    }
+
+    webhook::enqueue(
+        &mut tx,
+        WebhookEvent::CommentCreated,
+        WebhookSubject::Comment(comment_id),
+    )
+    .await?;
+
+    events.push(Event::Comment {
+        post_content_id: request.post_content_id,
+        comment_id,
+    });
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
+
     Ok(HttpResponse::Ok().json(Response::Success))
 }
@@ -0,0 +1,40 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn fetch_posted_by(db_pool: &MySqlPool, comment_id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            posted_by
+        FROM comments
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        comment_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.posted_by))
+}
+
+pub async fn delete_comment(
+    db_pool: &MySqlPool,
+    comment_id: Id,
+    deleted_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE comments
+        SET
+            deleted_by = ?,
+            deleted_at = NOW()
+        WHERE id = ?
+        ",
+        deleted_by,
+        comment_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,57 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{delete_comment, fetch_posted_by};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub comment_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success,
+}
+
+// Leaves a tombstone behind, replies to the comment stay where they are
+#[post("/api/post/comments/delete")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let posted_by = match fetch_posted_by(&state.db_pool, request.comment_id).await? {
+        Some(posted_by) => posted_by,
+        None => return Err(Error::Validation),
+    };
+
+    let can_delete = (posted_by == custom_claims.id && custom_claims.role.can_comment())
+        || custom_claims.role.can_moderate();
+
+    if !can_delete {
+        return Err(Error::Validation);
+    }
+
+    delete_comment(&state.db_pool, request.comment_id, custom_claims.id).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
@@ -0,0 +1,45 @@
+use crate::db::comment::content::Content;
+use crate::db::id::Id;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+pub async fn fetch_posted_by(db_pool: &MySqlPool, comment_id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            comments.posted_by
+        FROM comments
+            JOIN post_contents ON comments.post_content_id = post_contents.id
+            JOIN posts ON post_contents.post_id = posts.id
+        WHERE
+            comments.id = ?
+            AND comments.deleted_at IS NULL
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+        ",
+        comment_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.posted_by))
+}
+
+pub async fn update_comment(
+    tx: &mut Transaction<'_, MySql>,
+    comment_id: Id,
+    content: &Content,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE comments
+        SET
+            content = ?,
+            edited_at = NOW()
+        WHERE id = ?
+        ",
+        content.as_ref(),
+        comment_id
+    )
+    .execute(&mut *tx)
+    .await
+}
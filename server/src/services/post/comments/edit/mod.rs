@@ -0,0 +1,81 @@
+use crate::db::comment::content::Content;
+use crate::db::comment::revision::insert_revision;
+use crate::db::id::Id;
+use crate::db::mention::{update_mentions, Target as MentionTarget};
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{fetch_posted_by, update_comment};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub comment_id: Id,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { edited_at: i64 },
+}
+
+#[post("/api/post/comments/edit")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_comment() {
+        return Err(Error::Validation);
+    }
+
+    let content = Content::parse(request.content)?;
+
+    // Only the author can edit, not even moderators
+    let posted_by = match fetch_posted_by(&state.db_pool, request.comment_id).await? {
+        Some(posted_by) => posted_by,
+        None => return Err(Error::Validation),
+    };
+
+    if posted_by != custom_claims.id {
+        return Err(Error::Validation);
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    insert_revision(&mut tx, request.comment_id).await?;
+    update_comment(&mut tx, request.comment_id, &content).await?;
+    let events = update_mentions(
+        &mut tx,
+        MentionTarget::Comment(request.comment_id),
+        content.as_ref(),
+        custom_claims.id,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
+
+    let edited_at = Utc::now().timestamp_millis();
+
+    Ok(HttpResponse::Ok().json(Response::Success { edited_at }))
+}
@@ -6,23 +6,26 @@ use sqlx::Result;
 
 pub async fn fetch_page(
     db_pool: &MySqlPool,
+    fetcher_user_id: Option<Id>,
     post_content_id: Id,
+    all_languages: bool,
     query: &str,
     page: Page,
 ) -> Result<Vec<Comment>> {
-    let page = fetch_raw_page(db_pool, post_content_id, query, page).await?;
+    let page = fetch_raw_page(db_pool, post_content_id, all_languages, query, page).await?;
 
-    let page = page
-        .into_iter()
-        .map(|raw_comment| raw_comment.into())
-        .collect();
+    let mut comments = Vec::new();
+    for raw_comment in page {
+        comments.push(Comment::fetch_from_raw(db_pool, fetcher_user_id, raw_comment).await?);
+    }
 
-    Ok(page)
+    Ok(comments)
 }
 
 async fn fetch_raw_page(
     db_pool: &MySqlPool,
     post_content_id: Id,
+    all_languages: bool,
     query: &str,
     page: Page,
 ) -> Result<Vec<RawComment>> {
@@ -34,6 +37,8 @@ async fn fetch_raw_page(
         SELECT
             comments.id,
             comments.post_content_id,
+            post_contents.language_id,
+            languages.name AS language,
             comments.reply_to AS reply_to_id,
             reply_to_users.id AS reply_to_user_id,
             reply_to_users.handle AS reply_to_handle,
@@ -42,14 +47,28 @@ async fn fetch_raw_page(
             posted_by_users.id AS posted_by_id,
             posted_by_users.handle AS posted_by_handle,
             CONCAT(posted_by_files.id, \".\", posted_by_files.extension) AS `posted_by_profile_picture_file_name?`,
-            comments.posted_at
+            comments.posted_at,
+            comments.edited_at,
+            comments.deleted_at
         FROM comments
+            JOIN post_contents ON comments.post_content_id = post_contents.id
+            JOIN posts ON post_contents.post_id = posts.id
+            JOIN languages ON post_contents.language_id = languages.id
             LEFT JOIN comments AS reply_to ON comments.reply_to = reply_to.id 
             LEFT JOIN users AS reply_to_users ON reply_to.posted_by = reply_to_users.id
             LEFT JOIN files AS reply_to_files ON reply_to_users.profile_picture_file_id = reply_to_files.id
             JOIN users AS posted_by_users ON comments.posted_by = posted_by_users.id
             LEFT JOIN files AS posted_by_files ON posted_by_users.profile_picture_file_id = posted_by_files.id
-        WHERE (posted_by_users.handle LIKE ? OR comments.content LIKE ? OR reply_to_users.handle LIKE ?) AND comments.post_content_id = ?
+        WHERE (posted_by_users.handle LIKE ? OR (comments.content LIKE ? AND comments.deleted_at IS NULL) OR reply_to_users.handle LIKE ?)
+            AND post_contents.post_id = (
+                SELECT
+                    post_id
+                FROM post_contents AS viewed_post_contents
+                WHERE viewed_post_contents.id = ?
+            )
+            AND (? OR comments.post_content_id = ?)
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
         ORDER BY comments.posted_at ASC
         LIMIT ? OFFSET ?
         ",
@@ -57,6 +76,8 @@ async fn fetch_raw_page(
         &query,
         &query,
         post_content_id,
+        all_languages,
+        post_content_id,
         page.get_limit(),
         page.get_offset(),
     )
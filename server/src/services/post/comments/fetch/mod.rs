@@ -1,6 +1,7 @@
 use crate::db::id::Id;
 use crate::db::page::Page;
 use crate::error::Error;
+use crate::jwt;
 use crate::State;
 use actix_web::{
     post,
@@ -14,7 +15,12 @@ use db::fetch_page;
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
+    pub token: Option<String>,
+
     pub post_content_id: Id,
+    // Comments on the other translations of the post too
+    #[serde(default)]
+    pub all_languages: bool,
     pub query: String,
     pub limit: u64,
     pub offset: u64,
@@ -26,10 +32,13 @@ pub async fn service(
     Json(request): Json<Request>,
 ) -> Result<HttpResponse, Error> {
     let page = Page::new(request.limit, request.offset);
+    let fetcher_user_id = jwt::get_fetcher_user_id(&state.jwt_private_key, &request.token)?;
 
     let page = fetch_page(
         &state.db_pool,
+        fetcher_user_id,
         request.post_content_id,
+        request.all_languages,
         &request.query,
         page,
     )
@@ -1,2 +1,8 @@
 pub mod create;
+pub mod delete;
+pub mod edit;
 pub mod fetch;
+pub mod reactions;
+pub mod replies;
+pub mod revisions;
+pub mod thread;
@@ -0,0 +1,25 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+// Tombstones can't be reacted to
+pub async fn comment_exists(db_pool: &MySqlPool, comment_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            comments.id
+        FROM comments
+            JOIN post_contents ON comments.post_content_id = post_contents.id
+            JOIN posts ON post_contents.post_id = posts.id
+        WHERE
+            comments.id = ?
+            AND comments.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+            AND posts.deleted_at IS NULL
+        ",
+        comment_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
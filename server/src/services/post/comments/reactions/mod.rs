@@ -0,0 +1,66 @@
+use crate::db::id::Id;
+use crate::db::reaction::emoji::Emoji;
+use crate::db::reaction::{fetch_reactions, update_reaction, Reaction, Target as ReactionTarget};
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::comment_exists;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub comment_id: Id,
+    pub emoji: String,
+}
+
+// Reacting again with the same emoji takes the reaction back
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { reactions: Vec<Reaction> },
+}
+
+#[post("/api/post/comments/reactions")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_react() {
+        return Err(Error::Validation);
+    }
+
+    let emoji = Emoji::parse(request.emoji, &state.reactions)?;
+
+    if !comment_exists(&state.db_pool, request.comment_id).await? {
+        return Err(Error::Validation);
+    }
+
+    let target = ReactionTarget::Comment(request.comment_id);
+
+    let mut tx = state.db_pool.begin().await?;
+
+    update_reaction(&mut tx, target, custom_claims.id, &emoji).await?;
+
+    tx.commit().await?;
+
+    let reactions = fetch_reactions(&state.db_pool, target, Some(custom_claims.id)).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { reactions }))
+}
@@ -0,0 +1,37 @@
+use crate::db::comment::thread::Thread;
+use crate::db::id::Id;
+use crate::db::page::Page;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: Option<String>,
+
+    pub comment_id: Id,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+// Expands a comment of a thread
+#[post("/api/post/comments/replies")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let page = Page::new(request.limit, request.offset);
+    let fetcher_user_id = jwt::get_fetcher_user_id(&state.jwt_private_key, &request.token)?;
+
+    let replies =
+        Thread::fetch_replies_page(&state.db_pool, fetcher_user_id, request.comment_id, page)
+            .await?;
+
+    Ok(HttpResponse::Ok().json(replies))
+}
@@ -0,0 +1,49 @@
+use crate::db::comment::revision::Revision;
+use crate::db::id::Id;
+use chrono::NaiveDateTime;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug)]
+struct RawRevision {
+    id: Id,
+    content: String,
+    written_at: NaiveDateTime,
+}
+
+impl From<RawRevision> for Revision {
+    fn from(raw: RawRevision) -> Self {
+        Self {
+            id: raw.id,
+            content: raw.content,
+            written_at: raw.written_at.timestamp_millis(),
+        }
+    }
+}
+
+// History of deleted comments is gone along with them
+pub async fn fetch_revisions(db_pool: &MySqlPool, comment_id: Id) -> Result<Vec<Revision>> {
+    sqlx::query_as!(
+        RawRevision,
+        "
+        SELECT
+            comment_revisions.id,
+            comment_revisions.content,
+            comment_revisions.written_at
+        FROM comment_revisions
+            JOIN comments ON comment_revisions.comment_id = comments.id
+            JOIN post_contents ON comments.post_content_id = post_contents.id
+            JOIN posts ON post_contents.post_id = posts.id
+        WHERE
+            comment_revisions.comment_id = ?
+            AND comments.deleted_at IS NULL
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+        ORDER BY comment_revisions.written_at DESC
+        ",
+        comment_id
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|result| result.into_iter().map(|revision| revision.into()).collect())
+}
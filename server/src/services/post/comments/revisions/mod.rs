@@ -0,0 +1,27 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::Deserialize;
+
+mod db;
+use db::fetch_revisions;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub comment_id: Id,
+}
+
+#[post("/api/post/comments/revisions")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let revisions = fetch_revisions(&state.db_pool, request.comment_id).await?;
+
+    Ok(HttpResponse::Ok().json(revisions))
+}
@@ -0,0 +1,48 @@
+use crate::db::comment::thread::{Sort, Thread};
+use crate::db::id::Id;
+use crate::db::page::Page;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: Option<String>,
+
+    pub post_content_id: Id,
+    // Comments on the other translations of the post too
+    #[serde(default)]
+    pub all_languages: bool,
+    pub sort: Sort,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+// Top level comments with a preview of their replies,
+// unlike `comments::fetch` pages never split a thread
+#[post("/api/post/comments/thread")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let page = Page::new(request.limit, request.offset);
+    let fetcher_user_id = jwt::get_fetcher_user_id(&state.jwt_private_key, &request.token)?;
+
+    let threads = Thread::fetch_page(
+        &state.db_pool,
+        fetcher_user_id,
+        request.post_content_id,
+        request.all_languages,
+        request.sort,
+        page,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(threads))
+}
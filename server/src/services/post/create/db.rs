@@ -3,14 +3,15 @@ use crate::db::post::content::Content;
 use crate::db::post::description::Description;
 use crate::db::post::insert_post_content;
 use crate::db::post::insert_post_content_tags;
+use crate::db::post::revision::insert_revision;
 use crate::db::post::status::Status;
 use crate::db::post::tags::Tags;
 use crate::db::post::title::Title;
-use sqlx::mysql::{MySqlPool, MySqlQueryResult};
-use sqlx::Result;
+use sqlx::mysql::{MySql, MySqlQueryResult};
+use sqlx::{Result, Transaction};
 
 pub async fn insert_post(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     posted_by_user_id: Id,
     language_id: Id,
     title: &Title,
@@ -18,11 +19,11 @@ pub async fn insert_post(
     tags: &Tags,
     content: &Content,
 ) -> Result<Id> {
-    let posts_insert_result = insert_post_inner(db_pool, posted_by_user_id).await?;
+    let posts_insert_result = insert_post_inner(tx, posted_by_user_id).await?;
 
     let post_id = posts_insert_result.last_insert_id() as Id;
     let post_contents_insert_result = insert_post_content(
-        db_pool,
+        tx,
         post_id,
         language_id,
         title,
@@ -30,16 +31,23 @@ pub async fn insert_post(
         content,
         Status::Approved,
         posted_by_user_id,
+        false,
+        None,
     )
     .await?;
 
     let post_content_id = post_contents_insert_result.last_insert_id() as Id;
-    insert_post_content_tags(db_pool, post_content_id, tags).await?;
+    update_source_post_content_id(tx, post_id, post_content_id).await?;
+    insert_post_content_tags(tx, post_content_id, tags).await?;
+    insert_revision(tx, post_content_id, posted_by_user_id).await?;
 
     Ok(post_content_id)
 }
 
-async fn insert_post_inner(db_pool: &MySqlPool, posted_by_user_id: Id) -> Result<MySqlQueryResult> {
+async fn insert_post_inner(
+    tx: &mut Transaction<'_, MySql>,
+    posted_by_user_id: Id,
+) -> Result<MySqlQueryResult> {
     sqlx::query!(
         "
             INSERT INTO posts
@@ -55,6 +63,25 @@ async fn insert_post_inner(db_pool: &MySqlPool, posted_by_user_id: Id) -> Result
             ",
         posted_by_user_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
+    .await
+}
+
+async fn update_source_post_content_id(
+    tx: &mut Transaction<'_, MySql>,
+    post_id: Id,
+    post_content_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE posts
+        SET
+            source_post_content_id = ?
+        WHERE id = ?
+        ",
+        post_content_id,
+        post_id
+    )
+    .execute(&mut *tx)
     .await
 }
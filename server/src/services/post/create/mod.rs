@@ -1,10 +1,13 @@
 use crate::db::id::Id;
+use crate::db::mention::{update_mentions, Target as MentionTarget};
 use crate::db::post::content::Content;
 use crate::db::post::description::Description;
 use crate::db::post::tags::Tags;
 use crate::db::post::title::Title;
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
 use crate::error::Error;
 use crate::jwt;
+use crate::language_detection::{self, Mismatch, Mode as LanguageDetectionMode};
 use crate::State;
 use actix_web::{
     post,
@@ -31,7 +34,13 @@ pub struct Request {
 #[serde(tag = "tag")]
 pub enum Response {
     Unauthorized,
-    Success { post_content_id: Id },
+    LanguageMismatch {
+        mismatch: Mismatch,
+    },
+    Success {
+        post_content_id: Id,
+        language_mismatch: Option<Mismatch>,
+    },
 }
 
 #[post("/api/post/create")]
@@ -58,8 +67,24 @@ pub async fn service(
     // TODO: Update related fields
     // TODO: check upload limits
 
-    let post_content_id = insert_post(
+    let language_mismatch = language_detection::check(
         &state.db_pool,
+        state.language_detection,
+        request.language_id,
+        &[title.as_ref(), description.as_ref(), content.as_ref()],
+    )
+    .await?;
+
+    if matches!(state.language_detection, LanguageDetectionMode::Reject) {
+        if let Some(mismatch) = language_mismatch {
+            return Ok(HttpResponse::Ok().json(Response::LanguageMismatch { mismatch }));
+        }
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let post_content_id = insert_post(
+        &mut tx,
         custom_claims.id,
         request.language_id,
         &title,
@@ -69,5 +94,26 @@ pub async fn service(
     )
     .await?;
 
-    Ok(HttpResponse::Ok().json(Response::Success { post_content_id }))
+    let events = update_mentions(
+        &mut tx,
+        MentionTarget::PostContent(post_content_id),
+        content.as_ref(),
+        custom_claims.id,
+    )
+    .await?;
+    webhook::enqueue(
+        &mut tx,
+        WebhookEvent::PostCreated,
+        WebhookSubject::PostContent(post_content_id),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
+
+    Ok(HttpResponse::Ok().json(Response::Success {
+        post_content_id,
+        language_mismatch,
+    }))
 }
@@ -0,0 +1,42 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+pub async fn fetch_posted_by(db_pool: &MySqlPool, post_id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            posted_by
+        FROM posts
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        post_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.posted_by))
+}
+
+pub async fn delete_post(
+    tx: &mut Transaction<'_, MySql>,
+    post_id: Id,
+    deleted_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE posts
+        SET
+            deleted_by = ?,
+            deleted_at = NOW()
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        deleted_by,
+        post_id
+    )
+    .execute(&mut *tx)
+    .await
+}
@@ -0,0 +1,72 @@
+use crate::db::id::Id;
+use crate::db::post::RESTORE_WINDOW_DAYS;
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{delete_post, fetch_posted_by};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { restorable_until: i64 },
+}
+
+#[post("/api/post/delete")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_delete_posts() {
+        return Err(Error::Validation);
+    }
+
+    let posted_by = match fetch_posted_by(&state.db_pool, request.post_id).await? {
+        Some(posted_by) => posted_by,
+        None => return Err(Error::Validation),
+    };
+
+    if posted_by != custom_claims.id && !custom_claims.role.can_moderate() {
+        return Err(Error::Validation);
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    delete_post(&mut tx, request.post_id, custom_claims.id).await?;
+    webhook::enqueue(
+        &mut tx,
+        WebhookEvent::PostDeleted,
+        WebhookSubject::Post(request.post_id),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    let restorable_until = (Utc::now() + Duration::days(RESTORE_WINDOW_DAYS)).timestamp_millis();
+
+    Ok(HttpResponse::Ok().json(Response::Success { restorable_until }))
+}
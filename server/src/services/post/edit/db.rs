@@ -4,8 +4,8 @@ use crate::db::post::description::Description;
 use crate::db::post::insert_post_content_tags;
 use crate::db::post::tags::Tags;
 use crate::db::post::title::Title;
-use sqlx::mysql::{MySqlPool, MySqlQueryResult};
-use sqlx::Result;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
 
 pub async fn is_posted_by(db_pool: &MySqlPool, post_content_id: Id, user_id: Id) -> Result<bool> {
     sqlx::query!(
@@ -16,6 +16,8 @@ pub async fn is_posted_by(db_pool: &MySqlPool, post_content_id: Id, user_id: Id)
             JOIN post_contents ON posts.id = post_contents.post_id
         WHERE post_contents.id = ?
             AND posted_by = ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
         ",
         post_content_id,
         user_id
@@ -25,8 +27,23 @@ pub async fn is_posted_by(db_pool: &MySqlPool, post_content_id: Id, user_id: Id)
     .map(|result| result.is_some())
 }
 
+pub async fn fetch_post_id(db_pool: &MySqlPool, post_content_id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            post_id
+        FROM post_contents
+        WHERE id = ?
+        ",
+        post_content_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.post_id))
+}
+
 pub async fn update_post(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     post_content_id: Id,
     language_id: Id,
     title: &Title,
@@ -35,7 +52,7 @@ pub async fn update_post(
     content: &Content,
 ) -> Result<()> {
     update_post_inner(
-        db_pool,
+        tx,
         post_content_id,
         language_id,
         title,
@@ -44,14 +61,14 @@ pub async fn update_post(
     )
     .await?;
 
-    delete_post_content_tags(db_pool, post_content_id).await?;
-    insert_post_content_tags(db_pool, post_content_id, tags).await?;
+    delete_post_content_tags(tx, post_content_id).await?;
+    insert_post_content_tags(tx, post_content_id, tags).await?;
 
     Ok(())
 }
 
 async fn delete_post_content_tags(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     post_content_id: Id,
 ) -> Result<MySqlQueryResult> {
     sqlx::query!(
@@ -61,12 +78,12 @@ async fn delete_post_content_tags(
         ",
         post_content_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
 
 async fn update_post_inner(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     post_content_id: Id,
     language_id: Id,
     title: &Title,
@@ -89,6 +106,6 @@ async fn update_post_inner(
         content.as_ref(),
         post_content_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
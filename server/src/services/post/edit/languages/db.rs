@@ -1,5 +1,5 @@
 use crate::db::id::Id;
-use crate::db::language::Language;
+use crate::db::language::{Language, RawLanguage};
 use crate::db::post::status::Status as PostStatus;
 use sqlx::mysql::MySqlPool;
 use sqlx::Result;
@@ -10,11 +10,16 @@ pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id, query: &str) -> Res
     let query = format!("%{}%", query);
 
     sqlx::query_as!(
-        Language,
+        RawLanguage,
         "
         SELECT
             id,
-            name
+            code,
+            name,
+            native_name,
+            script,
+            direction,
+            parent_id
         FROM languages
         WHERE
             id NOT IN
@@ -34,6 +39,7 @@ pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id, query: &str) -> Res
                             )
                         AND post_contents.id != ?
                         AND status = ?
+                        AND post_contents.deleted_at IS NULL
                 )
             AND name LIKE ?
         LIMIT ?
@@ -46,4 +52,5 @@ pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id, query: &str) -> Res
     )
     .fetch_all(db_pool)
     .await
+    .map(|result| result.into_iter().map(|language| language.into()).collect())
 }
@@ -1,8 +1,13 @@
+use crate::db::glossary::{check as check_glossary, Warning};
 use crate::db::id::Id;
+use crate::db::mention::{update_mentions, Target as MentionTarget};
 use crate::db::post::content::Content;
 use crate::db::post::description::Description;
+use crate::db::post::revision::{insert_initial_revision, insert_revision};
 use crate::db::post::tags::Tags;
 use crate::db::post::title::Title;
+use crate::db::translation_memory::refresh_translation_memory;
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
 use crate::error::Error;
 use crate::jwt;
 use crate::State;
@@ -16,7 +21,7 @@ use serde::{Deserialize, Serialize};
 pub mod languages;
 
 mod db;
-use db::{is_posted_by, update_post};
+use db::{fetch_post_id, is_posted_by, update_post};
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
@@ -34,7 +39,7 @@ pub struct Request {
 #[serde(tag = "tag")]
 pub enum Response {
     Unauthorized,
-    Success,
+    Success { warnings: Vec<Warning> },
 }
 
 #[post("/api/post/edit")]
@@ -65,8 +70,25 @@ pub async fn service(
     // TODO: Update related fields
     // TODO: check upload limits
 
+    let warnings = match fetch_post_id(&state.db_pool, request.post_content_id).await? {
+        Some(post_id) => {
+            check_glossary(
+                &state.db_pool,
+                post_id,
+                request.language_id,
+                content.as_ref(),
+            )
+            .await?
+        }
+        None => Vec::new(),
+    };
+
+    let mut tx = state.db_pool.begin().await?;
+
+    insert_initial_revision(&mut tx, request.post_content_id).await?;
+
     update_post(
-        &state.db_pool,
+        &mut tx,
         request.post_content_id,
         request.language_id,
         &title,
@@ -76,5 +98,25 @@ pub async fn service(
     )
     .await?;
 
-    Ok(HttpResponse::Ok().json(Response::Success))
+    insert_revision(&mut tx, request.post_content_id, custom_claims.id).await?;
+    refresh_translation_memory(&mut tx, request.post_content_id).await?;
+    let events = update_mentions(
+        &mut tx,
+        MentionTarget::PostContent(request.post_content_id),
+        content.as_ref(),
+        custom_claims.id,
+    )
+    .await?;
+    webhook::enqueue(
+        &mut tx,
+        WebhookEvent::PostEdited,
+        WebhookSubject::PostContent(request.post_content_id),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
+
+    Ok(HttpResponse::Ok().json(Response::Success { warnings }))
 }
@@ -8,8 +8,10 @@ use sqlx::Result;
 
 pub async fn fetch_page(
     db_pool: &MySqlPool,
+    fetcher_user_id: Option<Id>,
     fetcher_language_ids: &Vec<Id>,
     fetcher_tag_ids: &Vec<Id>,
+    prefers_source_language: bool,
     query: &str,
     page: Page,
 ) -> Result<Vec<PostMeta>> {
@@ -29,6 +31,8 @@ pub async fn fetch_page(
             WHERE
                 status = ?
                 AND (title LIKE ? OR post_contents.description LIKE ? OR content LIKE ?)
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
                 AND language_id IN {}
                 AND tag_id IN {}
             ORDER BY translated_at DESC
@@ -52,9 +56,14 @@ pub async fn fetch_page(
         .map(|row| row.id)
         .collect();
 
-    let page =
-        PostMeta::fetch_from_post_ids_with_best_language(db_pool, fetcher_language_ids, &post_ids)
-            .await?;
+    let page = PostMeta::fetch_from_post_ids_with_best_language(
+        db_pool,
+        fetcher_user_id,
+        fetcher_language_ids,
+        prefers_source_language,
+        &post_ids,
+    )
+    .await?;
 
     Ok(page)
 }
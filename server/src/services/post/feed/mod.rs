@@ -1,15 +1,17 @@
+use crate::accept_language;
 use crate::db::id::Id;
 use crate::db::language::fetch_user_language_ids;
 use crate::db::page::Page;
 use crate::db::post::meta::Meta as PostMeta;
 use crate::db::tag::fetch_user_tag_ids;
+use crate::db::user::fetch_prefers_source_language;
 use crate::error::Error;
 use crate::jwt;
 use crate::State;
 use actix_web::{
     post,
     web::{Data, Json},
-    HttpResponse,
+    HttpRequest, HttpResponse,
 };
 use serde::{Deserialize, Serialize};
 
@@ -29,8 +31,12 @@ pub struct Request {
 #[serde(tag = "tag")]
 pub enum Preferences {
     Unauthorized {
+        // Taken from the `Accept-Language` header when empty
+        #[serde(default)]
         language_ids: Vec<Id>,
         tag_ids: Vec<Id>,
+        #[serde(default)]
+        prefers_source_language: bool,
     },
     Authorized {
         token: String,
@@ -47,32 +53,52 @@ pub enum Response {
 #[post("/api/post/feed")]
 pub async fn service(
     state: Data<State>,
+    http_request: HttpRequest,
     Json(request): Json<Request>,
 ) -> Result<HttpResponse, Error> {
-    let (fetcher_language_ids, fetcher_tag_ids) = match request.preferences {
-        Preferences::Unauthorized {
-            language_ids,
-            tag_ids,
-        } => (language_ids, tag_ids),
-        Preferences::Authorized { token } => {
-            let fetcher_user_id = match jwt::auth(&state.jwt_private_key, &token) {
-                Ok(custom_claims) => custom_claims.id,
-                Err(_) => return Ok(HttpResponse::Ok().json(Response::Unauthorized)),
-            };
+    let (fetcher_user_id, fetcher_language_ids, fetcher_tag_ids, prefers_source_language) =
+        match request.preferences {
+            Preferences::Unauthorized {
+                language_ids,
+                tag_ids,
+                prefers_source_language,
+            } => {
+                let language_ids = if language_ids.is_empty() {
+                    accept_language::fetch_language_ids(&state.db_pool, &http_request).await?
+                } else {
+                    language_ids
+                };
 
-            let language_ids = fetch_user_language_ids(&state.db_pool, fetcher_user_id).await?;
-            let tag_ids = fetch_user_tag_ids(&state.db_pool, fetcher_user_id).await?;
+                (None, language_ids, tag_ids, prefers_source_language)
+            }
+            Preferences::Authorized { token } => {
+                let fetcher_user_id = match jwt::auth(&state.jwt_private_key, &token) {
+                    Ok(custom_claims) => custom_claims.id,
+                    Err(_) => return Ok(HttpResponse::Ok().json(Response::Unauthorized)),
+                };
 
-            (language_ids, tag_ids)
-        }
-    };
+                let language_ids = fetch_user_language_ids(&state.db_pool, fetcher_user_id).await?;
+                let tag_ids = fetch_user_tag_ids(&state.db_pool, fetcher_user_id).await?;
+                let prefers_source_language =
+                    fetch_prefers_source_language(&state.db_pool, fetcher_user_id).await?;
+
+                (
+                    Some(fetcher_user_id),
+                    language_ids,
+                    tag_ids,
+                    prefers_source_language,
+                )
+            }
+        };
 
     let page = Page::new(request.limit, request.offset);
 
     let page = fetch_page(
         &state.db_pool,
+        fetcher_user_id,
         &fetcher_language_ids,
         &fetcher_tag_ids,
+        prefers_source_language,
         &request.query,
         page,
     )
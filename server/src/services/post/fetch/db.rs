@@ -1,5 +1,7 @@
 use crate::db::id::Id;
+use crate::db::mention::{fetch_mentions, Mention, Target as MentionTarget};
 use crate::db::post::status::Status;
+use crate::db::reaction::{fetch_reactions, Reaction, Target as ReactionTarget};
 use crate::db::tag::fetch_tags_by_post_content_id;
 use crate::db::translation::Translation;
 use crate::db::user::meta::Meta as UserMeta;
@@ -14,11 +16,19 @@ pub struct Post {
     pub post_content_id: Id,
     pub language_id: Id,
     pub language: String,
+    pub source_post_content_id: Option<Id>,
+    pub source_language_id: Option<Id>,
     pub translations: Vec<Translation>,
     pub title: String,
     pub description: String,
     pub tags: Vec<String>,
     pub content: String,
+    pub mentions: Vec<Mention>,
+    pub is_outdated: bool,
+    pub is_machine_translated: bool,
+    pub variant: Option<String>,
+    pub vote_count: i64,
+    pub reactions: Vec<Reaction>,
 
     pub posted_by: UserMeta,
     pub translated_by: UserMeta,
@@ -27,6 +37,7 @@ pub struct Post {
     pub translated_at: i64,
 
     pub is_bookmarked: bool,
+    pub is_voted: bool,
 }
 
 impl Post {
@@ -47,19 +58,40 @@ impl Post {
 
         let translations = Translation::fetch_by_post_id(db_pool, raw_post.id).await?;
         let tags = fetch_tags_by_post_content_id(db_pool, raw_post.post_content_id).await?;
+        let mentions = fetch_mentions(
+            db_pool,
+            MentionTarget::PostContent(raw_post.post_content_id),
+        )
+        .await?;
 
         let is_bookmarked = is_bookmarked(db_pool, fetcher_user_id, raw_post.id).await?;
+        let is_voted = is_voted(db_pool, fetcher_user_id, raw_post.post_content_id).await?;
+        // Nobody has the id 0, so nothing is marked for anonymous fetchers
+        let reactions = fetch_reactions(
+            db_pool,
+            ReactionTarget::Post(raw_post.id),
+            Some(fetcher_user_id),
+        )
+        .await?;
 
         let post = Self {
             id: raw_post.id,
             post_content_id: raw_post.post_content_id,
             language_id: raw_post.language_id,
             language: raw_post.language,
+            source_post_content_id: raw_post.source_post_content_id,
+            source_language_id: raw_post.source_language_id,
             translations,
             title: raw_post.title,
             description: raw_post.description,
             tags,
             content: raw_post.content,
+            mentions,
+            is_outdated: raw_post.newer_source_revision_count != 0,
+            is_machine_translated: raw_post.is_machine_translated != 0,
+            variant: raw_post.variant,
+            vote_count: raw_post.vote_count,
+            reactions,
 
             posted_by: UserMeta {
                 id: raw_post.posted_by_id,
@@ -76,6 +108,7 @@ impl Post {
             translated_at: raw_post.translated_at.timestamp_millis(),
 
             is_bookmarked,
+            is_voted,
         };
 
         Ok(Some(post))
@@ -88,9 +121,15 @@ struct RawPost {
     pub post_content_id: Id,
     pub language_id: Id,
     pub language: String,
+    pub source_post_content_id: Option<Id>,
+    pub source_language_id: Option<Id>,
     pub title: String,
     pub description: String,
     pub content: String,
+    pub newer_source_revision_count: i64,
+    pub is_machine_translated: i8,
+    pub variant: Option<String>,
+    pub vote_count: i64,
 
     pub posted_by_id: Id,
     pub posted_by_handle: String,
@@ -118,9 +157,25 @@ impl RawPost {
                 post_contents.id as post_content_id,
                 post_contents.language_id,
                 languages.name AS language,
+                posts.source_post_content_id,
+                (
+                    SELECT
+                        language_id
+                    FROM post_contents AS source_post_contents
+                    WHERE source_post_contents.id = posts.source_post_content_id
+                ) AS `source_language_id?`,
                 title,
                 post_contents.description,
                 content,
+                COALESCE(newer_source_revision_counts.newer_source_revision_count, 0) AS `newer_source_revision_count!`,
+                is_machine_translated,
+                variant,
+                (
+                    SELECT
+                        COUNT(*)
+                    FROM translation_votes
+                    WHERE translation_votes.post_content_id = post_contents.id
+                ) AS `vote_count!`,
                 users_posted_by.id as posted_by_id,
                 users_posted_by.handle as posted_by_handle,
                 CONCAT(files_posted_by.id, \".\", files_posted_by.extension) AS posted_by_profile_picture_file_name,
@@ -131,6 +186,7 @@ impl RawPost {
                 translated_at
             FROM posts
                 JOIN post_contents ON posts.id = post_contents.post_id
+                LEFT JOIN newer_source_revision_counts ON post_contents.id = newer_source_revision_counts.post_content_id
                 JOIN languages ON post_contents.language_id = languages.id
                 JOIN users as users_posted_by ON posts.posted_by = users_posted_by.id
                 LEFT JOIN files as files_posted_by ON users_posted_by.profile_picture_file_id = files_posted_by.id
@@ -139,6 +195,8 @@ impl RawPost {
             WHERE
                 post_contents.id = ?
                 AND (status = ? OR posted_by = ? OR translated_by = ?)
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
             ",
             post_content_id,
             Status::Approved.as_str(),
@@ -165,3 +223,19 @@ async fn is_bookmarked(db_pool: &MySqlPool, fetcher_user_id: Id, post_id: Id) ->
     .await
     .map(|result| result.is_some())
 }
+
+async fn is_voted(db_pool: &MySqlPool, fetcher_user_id: Id, post_content_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM translation_votes
+        WHERE voted_by = ? AND post_content_id = ?
+        ",
+        fetcher_user_id,
+        post_content_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
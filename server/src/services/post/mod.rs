@@ -2,8 +2,14 @@ pub mod all;
 pub mod bookmarks;
 pub mod comments;
 pub mod create;
+pub mod delete;
 pub mod edit;
 pub mod feed;
 pub mod fetch;
+pub mod parallel;
+pub mod reactions;
+pub mod restore;
+pub mod revisions;
 pub mod translate;
+pub mod translation_requests;
 pub mod translations;
@@ -0,0 +1,53 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug)]
+pub struct PostContent {
+    pub id: Id,
+    pub language_id: Id,
+    pub language: String,
+    pub title: String,
+    pub content: String,
+}
+
+impl PostContent {
+    // Same visibility rules as `post::fetch`
+    pub async fn fetch(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Option<Id>,
+        post_id: Id,
+        post_content_id: Id,
+    ) -> Result<Option<Self>> {
+        let fetcher_user_id = fetcher_user_id.unwrap_or(0);
+
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                post_contents.id,
+                post_contents.language_id,
+                languages.name AS language,
+                post_contents.title,
+                post_contents.content
+            FROM posts
+                JOIN post_contents ON posts.id = post_contents.post_id
+                JOIN languages ON post_contents.language_id = languages.id
+            WHERE
+                posts.id = ?
+                AND post_contents.id = ?
+                AND (status = ? OR posted_by = ? OR translated_by = ?)
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
+            ",
+            post_id,
+            post_content_id,
+            Status::Approved.as_str(),
+            fetcher_user_id,
+            fetcher_user_id
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
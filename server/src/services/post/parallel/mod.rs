@@ -0,0 +1,220 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::text::{align, paragraph, segment};
+use crate::State;
+use actix_web::{
+    post,
+    web::{self, Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+mod db;
+use db::PostContent;
+
+pub const POST_CONTENTS_MIN_AMOUNT: usize = 2;
+pub const POST_CONTENTS_MAX_AMOUNT: usize = 4;
+
+// Aligning takes time and memory proportional to the product
+// of the segment counts of the pivot and the other column
+pub const SEGMENTS_MAX_AMOUNT: usize = 256;
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(tag = "tag")]
+pub enum Unit {
+    Paragraph,
+    Sentence,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: Option<String>,
+
+    pub post_id: Id,
+    // The first one is the pivot every other one is aligned to
+    pub post_content_ids: Vec<Id>,
+    pub unit: Unit,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Column {
+    pub post_content_id: Id,
+    pub language_id: Id,
+    pub language: String,
+    pub title: String,
+    pub segments: Vec<String>,
+}
+
+// Segments `start..end` of a column correspond
+// to segments `pivot_start..pivot_end` of the pivot.
+// Either range is empty when a segment has no counterpart
+#[derive(Debug, Serialize)]
+pub struct Group {
+    pub pivot_start: usize,
+    pub pivot_end: usize,
+    pub start: usize,
+    pub end: usize,
+    pub confidence: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Alignment {
+    pub post_content_id: Id,
+    pub groups: Vec<Group>,
+    // Average confidence weighted by the length of the groups
+    pub confidence: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    NotFound,
+    // Some column has more than `SEGMENTS_MAX_AMOUNT` segments,
+    // with sentences paragraphs might still do
+    TooManySegments,
+    Success {
+        columns: Vec<Column>,
+        alignments: Vec<Alignment>,
+    },
+}
+
+#[post("/api/post/parallel")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let fetcher_user_id = match jwt::get_fetcher_user_id(&state.jwt_private_key, &request.token) {
+        Ok(fetcher_user_id) => fetcher_user_id,
+        Err(_) => return Ok(HttpResponse::Ok().json(Response::Unauthorized)),
+    };
+
+    let post_content_count = request.post_content_ids.len();
+    if post_content_count < POST_CONTENTS_MIN_AMOUNT
+        || post_content_count > POST_CONTENTS_MAX_AMOUNT
+    {
+        return Err(Error::Validation);
+    }
+
+    let mut unique_post_content_ids = HashSet::new();
+    if !request
+        .post_content_ids
+        .iter()
+        .all(|post_content_id| unique_post_content_ids.insert(post_content_id))
+    {
+        return Err(Error::Validation);
+    }
+
+    let mut columns = Vec::new();
+    for post_content_id in request.post_content_ids {
+        let post_content = match PostContent::fetch(
+            &state.db_pool,
+            fetcher_user_id,
+            request.post_id,
+            post_content_id,
+        )
+        .await?
+        {
+            Some(post_content) => post_content,
+            None => return Ok(HttpResponse::Ok().json(Response::NotFound)),
+        };
+
+        columns.push(Column {
+            post_content_id: post_content.id,
+            language_id: post_content.language_id,
+            language: post_content.language,
+            title: post_content.title,
+            segments: split(&post_content.content, request.unit),
+        });
+    }
+
+    if columns
+        .iter()
+        .any(|column| column.segments.len() > SEGMENTS_MAX_AMOUNT)
+    {
+        return Ok(HttpResponse::Ok().json(Response::TooManySegments));
+    }
+
+    // Aligning is CPU bound, so it's kept off the async workers
+    let (columns, alignments) = web::block(move || {
+        let alignments = align_columns(&columns);
+
+        (columns, alignments)
+    })
+    .await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success {
+        columns,
+        alignments,
+    }))
+}
+
+fn align_columns(columns: &[Column]) -> Vec<Alignment> {
+    let pivot = columns
+        .first()
+        .expect("There are at least `POST_CONTENTS_MIN_AMOUNT` columns");
+
+    columns
+        .iter()
+        .skip(1)
+        .map(|column| {
+            let groups: Vec<Group> = align::align(&pivot.segments, &column.segments)
+                .into_iter()
+                .map(|group| Group {
+                    pivot_start: group.source.start,
+                    pivot_end: group.source.end,
+                    start: group.target.start,
+                    end: group.target.end,
+                    confidence: group.confidence,
+                })
+                .collect();
+
+            let confidence = weighted_confidence(&groups, &pivot.segments, &column.segments);
+
+            Alignment {
+                post_content_id: column.post_content_id,
+                groups,
+                confidence,
+            }
+        })
+        .collect()
+}
+
+fn split(content: &str, unit: Unit) -> Vec<String> {
+    let paragraphs = paragraph::split(content)
+        .into_iter()
+        .map(|paragraph| paragraph.trim())
+        .filter(|paragraph| !paragraph.is_empty());
+
+    match unit {
+        Unit::Paragraph => paragraphs.map(|paragraph| paragraph.to_owned()).collect(),
+        Unit::Sentence => paragraphs
+            .flat_map(segment::sentences)
+            .map(|sentence| sentence.to_owned())
+            .collect(),
+    }
+}
+
+fn weighted_confidence(groups: &[Group], pivot: &[String], segments: &[String]) -> f64 {
+    let char_count = |segments: &[String]| -> usize {
+        segments.iter().map(|segment| segment.chars().count()).sum()
+    };
+
+    let mut total_weight = 0.0;
+    let mut total_confidence = 0.0;
+    for group in groups {
+        let weight = (char_count(&pivot[group.pivot_start..group.pivot_end])
+            + char_count(&segments[group.start..group.end])) as f64;
+
+        total_weight += weight;
+        total_confidence += weight * group.confidence;
+    }
+
+    if total_weight == 0.0 {
+        return 0.0;
+    }
+
+    total_confidence / total_weight
+}
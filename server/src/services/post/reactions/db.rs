@@ -0,0 +1,20 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+pub async fn post_exists(db_pool: &MySqlPool, post_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM posts
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        post_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
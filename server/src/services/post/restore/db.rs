@@ -0,0 +1,47 @@
+use crate::db::id::Id;
+use crate::db::post::RESTORE_WINDOW_DAYS;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+#[derive(Debug)]
+pub struct DeletedPost {
+    pub posted_by: Id,
+    pub deleted_by: Option<Id>,
+}
+
+impl DeletedPost {
+    pub async fn fetch_restorable(db_pool: &MySqlPool, post_id: Id) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                posted_by,
+                deleted_by
+            FROM posts
+            WHERE
+                id = ?
+                AND deleted_at > NOW() - INTERVAL ? DAY
+            ",
+            post_id,
+            RESTORE_WINDOW_DAYS
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
+
+pub async fn restore_post(db_pool: &MySqlPool, post_id: Id) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE posts
+        SET
+            deleted_by = NULL,
+            deleted_at = NULL
+        WHERE
+            id = ?
+        ",
+        post_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,63 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{restore_post, DeletedPost};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    NotRestorable,
+    Success,
+}
+
+#[post("/api/post/restore")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_delete_posts() {
+        return Err(Error::Validation);
+    }
+
+    let deleted_post = match DeletedPost::fetch_restorable(&state.db_pool, request.post_id).await? {
+        Some(deleted_post) => deleted_post,
+        None => return Ok(HttpResponse::Ok().json(Response::NotRestorable)),
+    };
+
+    // Authors can only undo their own deletions,
+    // posts taken down by moderators stay down
+    let is_own_deletion = deleted_post.posted_by == custom_claims.id
+        && deleted_post.deleted_by == Some(custom_claims.id);
+
+    if !is_own_deletion && !custom_claims.role.can_moderate() {
+        return Err(Error::Validation);
+    }
+
+    restore_post(&state.db_pool, request.post_id).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
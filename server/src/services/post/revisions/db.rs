@@ -0,0 +1,98 @@
+use crate::db::id::Id;
+use crate::db::page::Page;
+use crate::db::user::meta::Meta as UserMeta;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct Revision {
+    pub id: Id,
+    pub post_content_id: Id,
+    pub language_id: Id,
+    pub language: String,
+    pub title: String,
+
+    pub revised_by: UserMeta,
+    pub revised_at: i64,
+}
+
+impl Revision {
+    pub async fn fetch_page(
+        db_pool: &MySqlPool,
+        post_content_id: Id,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        RawRevision::fetch_page(db_pool, post_content_id, page)
+            .await
+            .map(|result| result.into_iter().map(|revision| revision.into()).collect())
+    }
+}
+
+impl From<RawRevision> for Revision {
+    fn from(raw: RawRevision) -> Self {
+        Self {
+            id: raw.id,
+            post_content_id: raw.post_content_id,
+            language_id: raw.language_id,
+            language: raw.language,
+            title: raw.title,
+
+            revised_by: UserMeta {
+                id: raw.revised_by_id,
+                handle: raw.revised_by_handle,
+                profile_picture_file_name: raw.revised_by_profile_picture_file_name,
+            },
+            revised_at: raw.revised_at.timestamp_millis(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawRevision {
+    pub id: Id,
+    pub post_content_id: Id,
+    pub language_id: Id,
+    pub language: String,
+    pub title: String,
+
+    pub revised_by_id: Id,
+    pub revised_by_handle: String,
+    pub revised_by_profile_picture_file_name: Option<String>,
+
+    pub revised_at: NaiveDateTime,
+}
+
+impl RawRevision {
+    async fn fetch_page(db_pool: &MySqlPool, post_content_id: Id, page: Page) -> Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                post_content_revisions.id,
+                post_content_revisions.post_content_id,
+                post_content_revisions.language_id,
+                languages.name AS language,
+                title,
+                users.id AS revised_by_id,
+                users.handle AS revised_by_handle,
+                CONCAT(files.id, \".\", files.extension) AS revised_by_profile_picture_file_name,
+                revised_at
+            FROM post_content_revisions
+                JOIN languages ON post_content_revisions.language_id = languages.id
+                JOIN users ON post_content_revisions.revised_by = users.id
+                LEFT JOIN files ON users.profile_picture_file_id = files.id
+            WHERE
+                post_content_revisions.post_content_id = ?
+            ORDER BY post_content_revisions.id DESC
+            LIMIT ? OFFSET ?
+            ",
+            post_content_id,
+            page.get_limit(),
+            page.get_offset()
+        )
+        .fetch_all(db_pool)
+        .await
+    }
+}
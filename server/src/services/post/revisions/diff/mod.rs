@@ -0,0 +1,129 @@
+use crate::db::id::Id;
+use crate::db::post::revision::{can_access_revisions, Revision};
+use crate::error::Error;
+use crate::jwt;
+use crate::text::diff::{unified, words, Chunk};
+use crate::State;
+use actix_web::{
+    error::BlockingError,
+    post,
+    web::{self, Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+pub const UNIFIED_CONTEXT: usize = 3;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub from_revision_id: Id,
+    pub to_revision_id: Id,
+    pub mode: Mode,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(tag = "tag")]
+pub enum Mode {
+    Unified,
+    Word,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagsDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Diff {
+    Unified {
+        title: String,
+        description: String,
+        content: String,
+        tags: TagsDiff,
+    },
+    Word {
+        title: Vec<Chunk>,
+        description: Vec<Chunk>,
+        content: Vec<Chunk>,
+        tags: TagsDiff,
+    },
+}
+
+impl Diff {
+    pub fn new(from: &Revision, to: &Revision, mode: Mode) -> Self {
+        let tags = TagsDiff {
+            added: to
+                .tags
+                .iter()
+                .filter(|tag| !from.tags.contains(tag))
+                .cloned()
+                .collect(),
+            removed: from
+                .tags
+                .iter()
+                .filter(|tag| !to.tags.contains(tag))
+                .cloned()
+                .collect(),
+        };
+
+        match mode {
+            Mode::Unified => Self::Unified {
+                title: unified(&from.title, &to.title, UNIFIED_CONTEXT),
+                description: unified(&from.description, &to.description, UNIFIED_CONTEXT),
+                content: unified(&from.content, &to.content, UNIFIED_CONTEXT),
+                tags,
+            },
+            Mode::Word => Self::Word {
+                title: words(&from.title, &to.title),
+                description: words(&from.description, &to.description),
+                content: words(&from.content, &to.content),
+                tags,
+            },
+        }
+    }
+
+    // Diffing is CPU bound, so it's kept off the async workers
+    pub async fn compute(from: Revision, to: Revision, mode: Mode) -> Result<Self, BlockingError> {
+        web::block(move || Self::new(&from, &to, mode)).await
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { diff: Diff },
+}
+
+#[post("/api/post/revisions/diff")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let from = Revision::fetch_by_id(&state.db_pool, request.from_revision_id).await?;
+    let to = Revision::fetch_by_id(&state.db_pool, request.to_revision_id).await?;
+
+    let (from, to) = match (from, to) {
+        (Some(from), Some(to)) if from.post_content_id == to.post_content_id => (from, to),
+        _ => return Err(Error::Validation),
+    };
+
+    if !can_access_revisions(&state.db_pool, from.post_content_id, custom_claims.id).await? {
+        return Err(Error::Validation);
+    }
+
+    let diff = Diff::compute(from, to, request.mode).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { diff }))
+}
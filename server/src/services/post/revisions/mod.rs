@@ -0,0 +1,57 @@
+use crate::db::id::Id;
+use crate::db::page::Page;
+use crate::db::post::revision::can_access_revisions;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod diff;
+pub mod rollback;
+
+mod db;
+use db::Revision;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { page: Vec<Revision> },
+}
+
+#[post("/api/post/revisions")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !can_access_revisions(&state.db_pool, request.post_content_id, custom_claims.id).await? {
+        return Err(Error::Validation);
+    }
+
+    let page = Page::new(request.limit, request.offset);
+
+    let page = Revision::fetch_page(&state.db_pool, request.post_content_id, page).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { page }))
+}
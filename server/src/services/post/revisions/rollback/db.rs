@@ -0,0 +1,56 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySql;
+use sqlx::{Result, Transaction};
+
+pub async fn restore_revision(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    revision_id: Id,
+) -> Result<()> {
+    sqlx::query!(
+        "
+        UPDATE post_contents
+            JOIN post_content_revisions ON post_contents.id = post_content_revisions.post_content_id
+        SET
+            post_contents.language_id = post_content_revisions.language_id,
+            post_contents.title = post_content_revisions.title,
+            post_contents.description = post_content_revisions.description,
+            post_contents.content = post_content_revisions.content
+        WHERE post_content_revisions.id = ?
+        ",
+        revision_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM post_content_tags
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        INSERT INTO post_content_tags
+        (
+            post_content_id,
+            tag_id
+        )
+        SELECT
+            ?,
+            tag_id
+        FROM post_content_revision_tags
+        WHERE post_content_revision_id = ?
+        ",
+        post_content_id,
+        revision_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::db::id::Id;
+use crate::db::mention::{update_mentions, Target as MentionTarget};
+use crate::db::post::revision::{can_access_revisions, insert_revision, Revision};
+use crate::db::translation_memory::refresh_translation_memory;
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::restore_revision;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub revision_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { revision_id: Id },
+}
+
+#[post("/api/post/revisions/rollback")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_edit_posts() {
+        return Err(Error::Validation);
+    }
+
+    let revision = match Revision::fetch_by_id(&state.db_pool, request.revision_id).await? {
+        Some(revision) => revision,
+        None => return Err(Error::Validation),
+    };
+
+    if !can_access_revisions(&state.db_pool, revision.post_content_id, custom_claims.id).await? {
+        return Err(Error::Validation);
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    restore_revision(&mut tx, revision.post_content_id, revision.id).await?;
+
+    // Rolling back doesn't rewrite history,
+    // it's recorded as a new revision on top
+    let revision_id = insert_revision(&mut tx, revision.post_content_id, custom_claims.id).await?;
+    refresh_translation_memory(&mut tx, revision.post_content_id).await?;
+    let events = update_mentions(
+        &mut tx,
+        MentionTarget::PostContent(revision.post_content_id),
+        &revision.content,
+        custom_claims.id,
+    )
+    .await?;
+    webhook::enqueue(
+        &mut tx,
+        WebhookEvent::PostEdited,
+        WebhookSubject::PostContent(revision.post_content_id),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
+
+    Ok(HttpResponse::Ok().json(Response::Success { revision_id }))
+}
@@ -0,0 +1,107 @@
+use crate::db::id::Id;
+use crate::db::post::claim::CLAIM_DURATION_HOURS;
+use chrono::NaiveDateTime;
+use sqlx::mysql::{MySql, MySqlPool};
+use sqlx::{Result, Transaction};
+
+pub async fn post_exists(db_pool: &MySqlPool, post_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM posts
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        post_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn language_exists(db_pool: &MySqlPool, language_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM languages
+        WHERE id = ?
+        ",
+        language_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+// Replaces the user's own or an expired claim, so claiming again
+// extends the claim and expired claims are taken over.
+// Returns `None` if someone else holds an active claim,
+// in which case the unique key rejects the new one
+pub async fn upsert_claim(
+    tx: &mut Transaction<'_, MySql>,
+    post_id: Id,
+    language_id: Id,
+    claimed_by: Id,
+) -> Result<Option<NaiveDateTime>> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_claims
+        WHERE
+            post_id = ?
+            AND language_id = ?
+            AND (expires_at <= NOW() OR claimed_by = ?)
+        ",
+        post_id,
+        language_id,
+        claimed_by
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let result = sqlx::query!(
+        "
+        INSERT IGNORE INTO translation_claims
+        (
+            post_id,
+            language_id,
+            claimed_by,
+            claimed_at,
+            expires_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            NOW(),
+            NOW() + INTERVAL ? HOUR
+        )
+        ",
+        post_id,
+        language_id,
+        claimed_by,
+        CLAIM_DURATION_HOURS
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(None);
+    }
+
+    sqlx::query!(
+        "
+        SELECT
+            expires_at
+        FROM translation_claims
+        WHERE id = ?
+        ",
+        result.last_insert_id()
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map(|row| Some(row.expires_at))
+}
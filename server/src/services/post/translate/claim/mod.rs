@@ -0,0 +1,86 @@
+use crate::db::id::Id;
+use crate::db::post::claim::Claim;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{language_exists, post_exists, upsert_claim};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_id: Id,
+    pub language_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Claimed { claim: Claim },
+    AlreadyClaimed,
+    Success { expires_at: i64 },
+}
+
+#[post("/api/post/translate/claim")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_translate_posts() {
+        return Err(Error::Validation);
+    }
+
+    if !post_exists(&state.db_pool, request.post_id).await? {
+        return Err(Error::Validation);
+    }
+
+    if !language_exists(&state.db_pool, request.language_id).await? {
+        return Err(Error::Validation);
+    }
+
+    if let Some(claim) =
+        Claim::fetch_active(&state.db_pool, request.post_id, request.language_id).await?
+    {
+        if claim.claimed_by.id != custom_claims.id {
+            return Ok(HttpResponse::Ok().json(Response::Claimed { claim }));
+        }
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let expires_at = upsert_claim(
+        &mut tx,
+        request.post_id,
+        request.language_id,
+        custom_claims.id,
+    )
+    .await?;
+
+    let expires_at = match expires_at {
+        Some(expires_at) => expires_at,
+        // Someone else claimed it since the check above
+        None => return Ok(HttpResponse::Ok().json(Response::AlreadyClaimed)),
+    };
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success {
+        expires_at: expires_at.timestamp_millis(),
+    }))
+}
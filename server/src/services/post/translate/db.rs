@@ -2,20 +2,19 @@ use crate::db::id::Id;
 use sqlx::mysql::MySqlPool;
 use sqlx::Result;
 
-pub async fn is_posted_by(db_pool: &MySqlPool, post_id: Id, user_id: Id) -> Result<bool> {
+pub async fn fetch_posted_by(db_pool: &MySqlPool, post_id: Id) -> Result<Option<Id>> {
     sqlx::query!(
         "
         SELECT
-            id
+            posted_by
         FROM posts
         WHERE
             id = ?
-            AND posted_by = ?
+            AND deleted_at IS NULL
         ",
-        post_id,
-        user_id
+        post_id
     )
     .fetch_optional(db_pool)
     .await
-    .map(|result| result.is_some())
+    .map(|result| result.map(|row| row.posted_by))
 }
@@ -0,0 +1,34 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug)]
+pub struct Source {
+    pub title: String,
+    pub description: String,
+    pub content: String,
+    pub language_code: String,
+}
+
+impl Source {
+    pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                title,
+                description,
+                content,
+                languages.code AS language_code
+            FROM post_contents
+                JOIN languages ON post_contents.language_id = languages.id
+            WHERE
+                post_contents.id = ?
+                AND deleted_at IS NULL
+            ",
+            post_content_id
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
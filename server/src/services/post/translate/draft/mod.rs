@@ -0,0 +1,210 @@
+use crate::db::id::Id;
+use crate::db::language::fetch_language_code;
+use crate::db::post::draft::record_draft;
+use crate::db::post::fetch_source_post_content_id;
+use crate::db::tag::fetch_tags_by_post_content_id;
+use crate::error::Error;
+use crate::jwt;
+use crate::text::paragraph;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::Source;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_id: Id,
+    pub language_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Draft {
+    pub title: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub content: String,
+}
+
+impl Draft {
+    // Translators return as many texts as they were given,
+    // in the order `texts` puts them in
+    fn from_translated(translated: Vec<String>, tag_count: usize) -> Self {
+        let mut translated = translated.into_iter();
+
+        let title = translated
+            .next()
+            .expect("Translated texts always include the title");
+        let description = translated
+            .next()
+            .expect("Translated texts always include the description");
+        let tags = translated.by_ref().take(tag_count).collect();
+        let content = paragraph::join(&translated.collect::<Vec<_>>());
+
+        Self {
+            title,
+            description,
+            tags,
+            content,
+        }
+    }
+}
+
+// Paragraphs are translated one by one to keep the layout intact
+fn texts(title: String, description: String, tags: Vec<String>, content: &str) -> Vec<String> {
+    let mut texts = vec![title, description];
+    texts.extend(tags);
+    texts.extend(
+        paragraph::split(content)
+            .into_iter()
+            .map(|paragraph| paragraph.to_owned()),
+    );
+
+    texts
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    // No machine translation provider is configured
+    Unavailable,
+    UnsupportedLanguage,
+    Failed,
+    Success { draft: Draft },
+}
+
+#[post("/api/post/translate/draft")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_translate_posts() {
+        return Err(Error::Validation);
+    }
+
+    let translator = match &state.translator {
+        Some(translator) => translator,
+        None => return Ok(HttpResponse::Ok().json(Response::Unavailable)),
+    };
+
+    let source_post_content_id =
+        match fetch_source_post_content_id(&state.db_pool, request.post_id).await? {
+            Some(source_post_content_id) => source_post_content_id,
+            None => return Err(Error::Validation),
+        };
+
+    let source = match Source::fetch(&state.db_pool, source_post_content_id).await? {
+        Some(source) => source,
+        None => return Err(Error::Validation),
+    };
+
+    let target_language_code =
+        match fetch_language_code(&state.db_pool, request.language_id).await? {
+            Some(target_language_code) => target_language_code,
+            None => return Ok(HttpResponse::Ok().json(Response::UnsupportedLanguage)),
+        };
+
+    let tags = fetch_tags_by_post_content_id(&state.db_pool, source_post_content_id).await?;
+    let tag_count = tags.len();
+
+    let texts = texts(source.title, source.description, tags, &source.content);
+
+    let translated = match translator
+        .translate(&texts, &source.language_code, &target_language_code)
+        .await
+    {
+        Ok(translated) => translated,
+        Err(error) => {
+            eprintln!(
+                "Failed to machine translate post {}: {error}",
+                request.post_id
+            );
+
+            return Ok(HttpResponse::Ok().json(Response::Failed));
+        }
+    };
+
+    let draft = Draft::from_translated(translated, tag_count);
+
+    // The submitted translation is flagged as machine translated based on this
+    record_draft(
+        &state.db_pool,
+        request.post_id,
+        request.language_id,
+        custom_claims.id,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { draft }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machine_translation::{fake::Fake, Translator};
+
+    async fn draft(title: &str, description: &str, tags: &[&str], content: &str) -> Draft {
+        let tags: Vec<String> = tags.iter().map(|tag| tag.to_string()).collect();
+        let tag_count = tags.len();
+        let texts = texts(title.to_owned(), description.to_owned(), tags, content);
+
+        let translated = Fake.translate(&texts, "en", "uk").await.unwrap();
+
+        Draft::from_translated(translated, tag_count)
+    }
+
+    #[tokio::test]
+    async fn translates_every_field() {
+        let draft = draft("Title", "Description", &["rust", "mysql"], "Content").await;
+
+        assert_eq!(draft.title, "[uk] Title");
+        assert_eq!(draft.description, "[uk] Description");
+        assert_eq!(draft.tags, vec!["[uk] rust", "[uk] mysql"]);
+        assert_eq!(draft.content, "[uk] Content");
+    }
+
+    #[tokio::test]
+    async fn keeps_paragraphs_apart() {
+        let draft = draft("Title", "Description", &["rust"], "First\n\nSecond\nline").await;
+
+        assert_eq!(draft.content, "[uk] First\n\n[uk] Second\nline");
+    }
+
+    #[tokio::test]
+    async fn handles_posts_without_tags() {
+        let draft = draft("Title", "Description", &[], "First\n\nSecond").await;
+
+        assert!(draft.tags.is_empty());
+        assert_eq!(draft.content, "[uk] First\n\n[uk] Second");
+    }
+
+    #[test]
+    fn orders_texts_for_translation() {
+        let texts = texts(
+            "Title".to_owned(),
+            "Description".to_owned(),
+            vec!["rust".to_owned()],
+            "First\n\nSecond",
+        );
+
+        assert_eq!(
+            texts,
+            vec!["Title", "Description", "rust", "First", "Second"]
+        );
+    }
+}
@@ -1,40 +1,81 @@
 use crate::db::id::Id;
-use crate::db::language::Language;
-use crate::db::post::status::Status as PostStatus;
+use crate::db::post::claim::Claim;
+use crate::db::user::meta::Meta as UserMeta;
+use chrono::NaiveDateTime;
+use serde::Serialize;
 use sqlx::mysql::MySqlPool;
 use sqlx::Result;
 
 pub const SUGGESTIONS_MAX_AMOUNT: u64 = 5;
 
+#[derive(Debug, Serialize)]
+pub struct Language {
+    pub id: Id,
+    pub name: String,
+    pub claim: Option<Claim>,
+}
+
+impl From<RawLanguage> for Language {
+    fn from(raw: RawLanguage) -> Self {
+        Self {
+            id: raw.id,
+            name: raw.name,
+            claim: raw.claimed_by_id.map(|claimed_by_id| Claim {
+                claimed_by: UserMeta {
+                    id: claimed_by_id,
+                    handle: raw
+                        .claimed_by_handle
+                        .expect("`claimed_by_handle` is tied to `claimed_by_id` in DB"),
+                    profile_picture_file_name: raw.claimed_by_profile_picture_file_name,
+                },
+                expires_at: raw
+                    .expires_at
+                    .expect("`expires_at` is tied to `claimed_by_id` in DB")
+                    .timestamp_millis(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawLanguage {
+    id: Id,
+    name: String,
+    claimed_by_id: Option<Id>,
+    claimed_by_handle: Option<String>,
+    claimed_by_profile_picture_file_name: Option<String>,
+    expires_at: Option<NaiveDateTime>,
+}
+
 pub async fn fetch(db_pool: &MySqlPool, post_id: Id, query: &str) -> Result<Vec<Language>> {
     let query = format!("%{}%", query);
 
     // TODO: Refactor all where clauses to begin on new line
     sqlx::query_as!(
-        Language,
+        RawLanguage,
         "
             SELECT
-                id,
-                name
+                languages.id,
+                languages.name,
+                users.id AS `claimed_by_id?`,
+                users.handle AS `claimed_by_handle?`,
+                CONCAT(files.id, \".\", files.extension) AS `claimed_by_profile_picture_file_name?`,
+                translation_claims.expires_at AS `expires_at?`
             FROM languages
+                LEFT JOIN translation_claims ON languages.id = translation_claims.language_id
+                    AND translation_claims.post_id = ?
+                    AND translation_claims.expires_at > NOW()
+                LEFT JOIN users ON translation_claims.claimed_by = users.id
+                LEFT JOIN files ON users.profile_picture_file_id = files.id
             WHERE
-                id NOT IN (
-                    SELECT
-                        language_id
-                    FROM posts
-                        JOIN post_contents ON posts.id = post_contents.post_id
-                    WHERE 
-                        posts.id = ?
-                        AND status = ?
-                )
-                AND name LIKE ?
+                languages.name LIKE ?
             LIMIT ?
             ",
         post_id,
-        PostStatus::Approved.as_str(),
         query,
         SUGGESTIONS_MAX_AMOUNT
     )
     .fetch_all(db_pool)
     .await
+    .map(|result| result.into_iter().map(|language| language.into()).collect())
 }
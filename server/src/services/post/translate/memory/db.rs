@@ -0,0 +1,91 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status as PostStatus;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+pub const MATCHES_MAX_AMOUNT: usize = 10;
+pub const FUZZY_CANDIDATES_MAX_AMOUNT: u64 = 100;
+
+#[derive(Debug)]
+pub struct Unit {
+    pub post_content_id: Id,
+    pub source_segment: String,
+    pub target_segment: String,
+}
+
+impl Unit {
+    pub async fn fetch_exact(
+        db_pool: &MySqlPool,
+        source_language_id: Id,
+        target_language_id: Id,
+        segment: &str,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                translation_memory.post_content_id,
+                translation_memory.source_segment,
+                translation_memory.target_segment
+            FROM translation_memory
+                JOIN post_contents ON translation_memory.post_content_id = post_contents.id
+            WHERE
+                translation_memory.source_language_id = ?
+                AND translation_memory.target_language_id = ?
+                AND translation_memory.source_segment = ?
+                AND post_contents.status = ?
+                AND post_contents.deleted_at IS NULL
+            ORDER BY translation_memory.created_at DESC
+            LIMIT ?
+            ",
+            source_language_id,
+            target_language_id,
+            segment,
+            PostStatus::Approved.as_str(),
+            MATCHES_MAX_AMOUNT as u64
+        )
+        .fetch_all(db_pool)
+        .await
+    }
+
+    // Segments whose length is too far off can't be similar enough,
+    // so they are filtered out before similarity is computed
+    pub async fn fetch_fuzzy_candidates(
+        db_pool: &MySqlPool,
+        source_language_id: Id,
+        target_language_id: Id,
+        segment: &str,
+        min_char_count: u64,
+        max_char_count: u64,
+    ) -> Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                translation_memory.post_content_id,
+                translation_memory.source_segment,
+                translation_memory.target_segment
+            FROM translation_memory
+                JOIN post_contents ON translation_memory.post_content_id = post_contents.id
+            WHERE
+                translation_memory.source_language_id = ?
+                AND translation_memory.target_language_id = ?
+                AND translation_memory.source_segment != ?
+                AND CHAR_LENGTH(translation_memory.source_segment) BETWEEN ? AND ?
+                AND post_contents.status = ?
+                AND post_contents.deleted_at IS NULL
+            ORDER BY translation_memory.created_at DESC
+            LIMIT ?
+            ",
+            source_language_id,
+            target_language_id,
+            segment,
+            min_char_count,
+            max_char_count,
+            PostStatus::Approved.as_str(),
+            FUZZY_CANDIDATES_MAX_AMOUNT
+        )
+        .fetch_all(db_pool)
+        .await
+    }
+}
@@ -0,0 +1,140 @@
+use crate::db::id::Id;
+use crate::db::translation_memory::SEGMENT_MAX_CHAR_COUNT;
+use crate::error::Error;
+use crate::jwt;
+use crate::text::similarity::similarity;
+use crate::State;
+use actix_web::{
+    post,
+    web::{self, Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{Unit, MATCHES_MAX_AMOUNT};
+
+// Fuzzy matches below this are more noise than help
+const MIN_SIMILARITY: f64 = 0.7;
+// Similarity is quadratic in segment length,
+// longer segments are only matched exactly
+const FUZZY_SEGMENT_MAX_CHAR_COUNT: usize = 512;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub source_language_id: Id,
+    pub target_language_id: Id,
+    pub segment: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Match {
+    pub post_content_id: Id,
+    pub source: String,
+    pub target: String,
+    pub similarity: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { matches: Vec<Match> },
+}
+
+#[post("/api/post/translate/memory")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_translate_posts() {
+        return Err(Error::Validation);
+    }
+
+    let segment = request.segment.trim();
+    let char_count = segment.chars().count();
+
+    if char_count == 0 || char_count > SEGMENT_MAX_CHAR_COUNT {
+        return Err(Error::Validation);
+    }
+
+    let exact_matches: Vec<Match> = Unit::fetch_exact(
+        &state.db_pool,
+        request.source_language_id,
+        request.target_language_id,
+        segment,
+    )
+    .await?
+    .into_iter()
+    .map(|unit| Match {
+        post_content_id: unit.post_content_id,
+        source: unit.source_segment,
+        target: unit.target_segment,
+        similarity: 1.0,
+    })
+    .collect();
+
+    let fuzzy_matches = if char_count <= FUZZY_SEGMENT_MAX_CHAR_COUNT {
+        let min_char_count = (char_count as f64 * MIN_SIMILARITY).ceil() as u64;
+        let max_char_count = (char_count as f64 / MIN_SIMILARITY).floor() as u64;
+
+        let candidates = Unit::fetch_fuzzy_candidates(
+            &state.db_pool,
+            request.source_language_id,
+            request.target_language_id,
+            segment,
+            min_char_count,
+            max_char_count,
+        )
+        .await?;
+
+        let segment = segment.to_owned();
+        web::block(move || rank_fuzzy_matches(&segment, candidates)).await?
+    } else {
+        Vec::new()
+    };
+
+    // The same pair of segments tends to come up in many posts
+    let mut matches: Vec<Match> = Vec::new();
+    for candidate in exact_matches.into_iter().chain(fuzzy_matches) {
+        if matches.len() == MATCHES_MAX_AMOUNT {
+            break;
+        }
+
+        let is_duplicate = matches.iter().any(|existing| {
+            existing.source == candidate.source && existing.target == candidate.target
+        });
+
+        if !is_duplicate {
+            matches.push(candidate);
+        }
+    }
+
+    Ok(HttpResponse::Ok().json(Response::Success { matches }))
+}
+
+fn rank_fuzzy_matches(segment: &str, candidates: Vec<Unit>) -> Vec<Match> {
+    let mut fuzzy_matches: Vec<Match> = candidates
+        .into_iter()
+        .map(|unit| Match {
+            similarity: similarity(segment, &unit.source_segment),
+            post_content_id: unit.post_content_id,
+            source: unit.source_segment,
+            target: unit.target_segment,
+        })
+        .filter(|fuzzy_match| fuzzy_match.similarity >= MIN_SIMILARITY)
+        .collect();
+
+    fuzzy_matches.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+
+    fuzzy_matches
+}
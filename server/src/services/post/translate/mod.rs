@@ -1,12 +1,26 @@
+use crate::db::glossary::{check as check_glossary, Warning};
 use crate::db::id::Id;
+use crate::db::mention::{update_mentions, Target as MentionTarget};
+use crate::db::notification::{kind::Kind as NotificationKind, notify};
+use crate::db::post::claim::{release_claim, Claim};
 use crate::db::post::content::Content;
 use crate::db::post::description::Description;
+use crate::db::post::draft::has_recent_draft;
+use crate::db::post::revision::insert_revision;
 use crate::db::post::status::Status as PostStatus;
 use crate::db::post::tags::Tags;
 use crate::db::post::title::Title;
-use crate::db::post::{insert_post_content, insert_post_content_tags};
+use crate::db::post::translation_request::fulfill_translation_requests;
+use crate::db::post::variant::Variant;
+use crate::db::post::{
+    fetch_source_post_content_id, insert_post_content, insert_post_content_tags,
+    update_source_revision,
+};
+use crate::db::translation_memory::update_translation_memory;
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
 use crate::error::Error;
 use crate::jwt;
+use crate::language_detection::{self, Mismatch, Mode as LanguageDetectionMode};
 use crate::State;
 use actix_web::{
     post,
@@ -15,10 +29,14 @@ use actix_web::{
 };
 use serde::{Deserialize, Serialize};
 
+pub mod claim;
+pub mod draft;
 pub mod languages;
+pub mod memory;
+pub mod release;
 
 mod db;
-use db::is_posted_by;
+use db::fetch_posted_by;
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
@@ -30,13 +48,30 @@ pub struct Request {
     pub description: String,
     pub tags: Vec<String>,
     pub content: String,
+    // Set when the translation started out as a machine translated draft
+    // from somewhere else, drafts issued by this server are tracked on their own
+    #[serde(default)]
+    pub is_machine_translated: bool,
+    // Lets several translations into the same language be approved side by side
+    #[serde(default)]
+    pub variant: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(tag = "tag")]
 pub enum Response {
     Unauthorized,
-    Success { post_content_id: Id },
+    Claimed {
+        claim: Claim,
+    },
+    LanguageMismatch {
+        mismatch: Mismatch,
+    },
+    Success {
+        post_content_id: Id,
+        warnings: Vec<Warning>,
+        language_mismatch: Option<Mismatch>,
+    },
 }
 
 #[post("/api/post/translate")]
@@ -59,31 +94,142 @@ pub async fn service(
     let description = Description::parse(request.description)?;
     let tags = Tags::parse(request.tags)?;
     let content = Content::parse(request.content)?;
+    let variant = request.variant.map(Variant::parse).transpose()?;
 
     // TODO: Update related fields
     // TODO: check upload limits
 
-    let status = if is_posted_by(&state.db_pool, request.post_id, custom_claims.id).await? {
+    let posted_by = match fetch_posted_by(&state.db_pool, request.post_id).await? {
+        Some(posted_by) => posted_by,
+        None => return Err(Error::Validation),
+    };
+
+    if let Some(claim) =
+        Claim::fetch_active(&state.db_pool, request.post_id, request.language_id).await?
+    {
+        if claim.claimed_by.id != custom_claims.id {
+            return Ok(HttpResponse::Ok().json(Response::Claimed { claim }));
+        }
+    }
+
+    let language_mismatch = language_detection::check(
+        &state.db_pool,
+        state.language_detection,
+        request.language_id,
+        &[title.as_ref(), description.as_ref(), content.as_ref()],
+    )
+    .await?;
+
+    if matches!(state.language_detection, LanguageDetectionMode::Reject) {
+        if let Some(mismatch) = language_mismatch {
+            return Ok(HttpResponse::Ok().json(Response::LanguageMismatch { mismatch }));
+        }
+    }
+
+    let status = if posted_by == custom_claims.id {
         PostStatus::Approved
     } else {
         PostStatus::Pending
     };
 
-    let post_content_id = insert_post_content(
+    let source_post_content_id =
+        fetch_source_post_content_id(&state.db_pool, request.post_id).await?;
+
+    let is_machine_translated = request.is_machine_translated
+        || has_recent_draft(
+            &state.db_pool,
+            request.post_id,
+            request.language_id,
+            custom_claims.id,
+        )
+        .await?;
+
+    let warnings = check_glossary(
         &state.db_pool,
         request.post_id,
         request.language_id,
+        content.as_ref(),
+    )
+    .await?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let post_content_id = insert_post_content(
+        &mut tx,
+        request.post_id,
+        request.language_id,
         &title,
         &description,
         &content,
         status,
         custom_claims.id,
+        is_machine_translated,
+        variant.as_ref(),
     )
     .await?;
 
     let post_content_id = post_content_id.last_insert_id() as Id;
 
-    insert_post_content_tags(&state.db_pool, post_content_id, &tags).await?;
+    insert_post_content_tags(&mut tx, post_content_id, &tags).await?;
+    insert_revision(&mut tx, post_content_id, custom_claims.id).await?;
+    let mut events = update_mentions(
+        &mut tx,
+        MentionTarget::PostContent(post_content_id),
+        content.as_ref(),
+        custom_claims.id,
+    )
+    .await?;
+
+    if let Some(source_post_content_id) = source_post_content_id {
+        update_source_revision(&mut tx, post_content_id, source_post_content_id).await?;
+    }
+
+    release_claim(
+        &mut tx,
+        request.post_id,
+        request.language_id,
+        custom_claims.id,
+    )
+    .await?;
+    webhook::enqueue(
+        &mut tx,
+        WebhookEvent::TranslationSubmitted,
+        WebhookSubject::PostContent(post_content_id),
+    )
+    .await?;
+
+    // Translations by the author are approved right away,
+    // the rest waits for the author to review them
+    if matches!(status, PostStatus::Approved) {
+        events.extend(fulfill_translation_requests(&mut tx, post_content_id).await?);
+        update_translation_memory(&mut tx, post_content_id).await?;
+        webhook::enqueue(
+            &mut tx,
+            WebhookEvent::TranslationApproved,
+            WebhookSubject::PostContent(post_content_id),
+        )
+        .await?;
+    } else {
+        let event = notify(
+            &mut tx,
+            posted_by,
+            NotificationKind::TranslationSubmitted,
+            custom_claims.id,
+            Some(post_content_id),
+            None,
+        )
+        .await?;
+
+        events.extend(event);
+    }
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
 
-    Ok(HttpResponse::Ok().json(Response::Success { post_content_id }))
+    Ok(HttpResponse::Ok().json(Response::Success {
+        post_content_id,
+        warnings,
+        language_mismatch,
+    }))
 }
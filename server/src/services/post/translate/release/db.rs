@@ -0,0 +1,27 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn delete_claim(
+    db_pool: &MySqlPool,
+    post_id: Id,
+    language_id: Id,
+    user_id: Id,
+    is_moderator: bool,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_claims
+        WHERE
+            post_id = ?
+            AND language_id = ?
+            AND (claimed_by = ? OR ?)
+        ",
+        post_id,
+        language_id,
+        user_id,
+        is_moderator
+    )
+    .execute(db_pool)
+    .await
+}
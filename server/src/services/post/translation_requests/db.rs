@@ -0,0 +1,153 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status as PostStatus;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+pub async fn post_exists(db_pool: &MySqlPool, post_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM posts
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        post_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn language_exists(db_pool: &MySqlPool, language_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM languages
+        WHERE id = ?
+        ",
+        language_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn is_translated(db_pool: &MySqlPool, post_id: Id, language_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM post_contents
+        WHERE
+            post_id = ?
+            AND language_id = ?
+            AND status = ?
+            AND deleted_at IS NULL
+        LIMIT 1
+        ",
+        post_id,
+        language_id,
+        PostStatus::Approved.as_str()
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn update_translation_request(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    post_id: Id,
+    language_id: Id,
+) -> Result<MySqlQueryResult> {
+    if translation_request_exists(tx, user_id, post_id, language_id).await? {
+        delete_translation_request(tx, user_id, post_id, language_id).await
+    } else {
+        create_translation_request(tx, user_id, post_id, language_id).await
+    }
+}
+
+async fn create_translation_request(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    post_id: Id,
+    language_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        INSERT INTO translation_requests
+        (
+            post_id,
+            language_id,
+            requested_by,
+            requested_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        post_id,
+        language_id,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await
+}
+
+// Fulfilled requests are kept around, so that
+// requesters can see what came out of them
+async fn delete_translation_request(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    post_id: Id,
+    language_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_requests
+        WHERE
+            requested_by = ?
+            AND post_id = ?
+            AND language_id = ?
+            AND fulfilled_at IS NULL
+        ",
+        user_id,
+        post_id,
+        language_id
+    )
+    .execute(&mut *tx)
+    .await
+}
+
+async fn translation_request_exists(
+    tx: &mut Transaction<'_, MySql>,
+    user_id: Id,
+    post_id: Id,
+    language_id: Id,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM translation_requests
+        WHERE
+            requested_by = ?
+            AND post_id = ?
+            AND language_id = ?
+            AND fulfilled_at IS NULL
+        ",
+        user_id,
+        post_id,
+        language_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.is_some())
+}
@@ -0,0 +1,74 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod queue;
+
+mod db;
+use db::{is_translated, language_exists, post_exists, update_translation_request};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_id: Id,
+    pub language_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    AlreadyTranslated,
+    Success,
+}
+
+#[post("/api/post/translation-requests")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_request_translations() {
+        return Err(Error::Validation);
+    }
+
+    if !post_exists(&state.db_pool, request.post_id).await? {
+        return Err(Error::Validation);
+    }
+
+    if !language_exists(&state.db_pool, request.language_id).await? {
+        return Err(Error::Validation);
+    }
+
+    if is_translated(&state.db_pool, request.post_id, request.language_id).await? {
+        return Ok(HttpResponse::Ok().json(Response::AlreadyTranslated));
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    update_translation_request(
+        &mut tx,
+        custom_claims.id,
+        request.post_id,
+        request.language_id,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
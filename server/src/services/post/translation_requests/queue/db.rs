@@ -0,0 +1,127 @@
+use crate::db::id::Id;
+use crate::db::page::Page;
+use crate::db::post::status::Status as PostStatus;
+use crate::db::user::meta::Meta as UserMeta;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct Entry {
+    pub post_id: Id,
+    pub language_id: Id,
+    pub language: String,
+    pub title: String,
+    pub posted_by: UserMeta,
+    pub request_count: i64,
+    pub last_requested_at: i64,
+}
+
+impl Entry {
+    pub async fn fetch_page(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Id,
+        only_my_languages: bool,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        RawEntry::fetch_page(db_pool, fetcher_user_id, only_my_languages, page)
+            .await
+            .map(|result| result.into_iter().map(|entry| entry.into()).collect())
+    }
+}
+
+impl From<RawEntry> for Entry {
+    fn from(raw: RawEntry) -> Self {
+        Self {
+            post_id: raw.post_id,
+            language_id: raw.language_id,
+            language: raw.language,
+            title: raw.title,
+            posted_by: UserMeta {
+                id: raw.posted_by_id,
+                handle: raw.posted_by_handle,
+                profile_picture_file_name: raw.posted_by_profile_picture_file_name,
+            },
+            request_count: raw.request_count,
+            last_requested_at: raw.last_requested_at.timestamp_millis(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawEntry {
+    post_id: Id,
+    language_id: Id,
+    language: String,
+    title: String,
+    posted_by_id: Id,
+    posted_by_handle: String,
+    posted_by_profile_picture_file_name: Option<String>,
+    request_count: i64,
+    last_requested_at: NaiveDateTime,
+}
+
+impl RawEntry {
+    async fn fetch_page(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Id,
+        only_my_languages: bool,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        // Posts are titled after the content they were written in
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                translation_requests.post_id,
+                translation_requests.language_id,
+                ANY_VALUE(languages.name) AS `language!`,
+                ANY_VALUE(source_post_contents.title) AS `title!`,
+                ANY_VALUE(users.id) AS `posted_by_id!`,
+                ANY_VALUE(users.handle) AS `posted_by_handle!`,
+                ANY_VALUE(CONCAT(files.id, \".\", files.extension)) AS `posted_by_profile_picture_file_name?`,
+                COUNT(*) AS request_count,
+                MAX(translation_requests.requested_at) AS `last_requested_at!`
+            FROM translation_requests
+                JOIN languages ON translation_requests.language_id = languages.id
+                JOIN posts ON translation_requests.post_id = posts.id
+                JOIN post_contents AS source_post_contents ON source_post_contents.id = posts.source_post_content_id
+                JOIN users ON posts.posted_by = users.id
+                LEFT JOIN files ON users.profile_picture_file_id = files.id
+            WHERE
+                translation_requests.fulfilled_at IS NULL
+                AND posts.deleted_at IS NULL
+                AND NOT EXISTS (
+                    SELECT
+                        id
+                    FROM post_contents
+                    WHERE
+                        post_id = translation_requests.post_id
+                        AND language_id = translation_requests.language_id
+                        AND status = ?
+                        AND deleted_at IS NULL
+                )
+                AND (
+                    NOT ?
+                    OR translation_requests.language_id IN (
+                        SELECT
+                            language_id
+                        FROM user_languages
+                        WHERE user_id = ?
+                    )
+                )
+            GROUP BY translation_requests.post_id, translation_requests.language_id
+            ORDER BY request_count DESC, MAX(translation_requests.requested_at) DESC
+            LIMIT ? OFFSET ?
+            ",
+            PostStatus::Approved.as_str(),
+            only_my_languages,
+            fetcher_user_id,
+            page.get_limit(),
+            page.get_offset()
+        )
+        .fetch_all(db_pool)
+        .await
+    }
+}
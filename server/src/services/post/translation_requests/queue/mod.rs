@@ -0,0 +1,59 @@
+use crate::db::page::Page;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::Entry;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    // Only show requests for the languages the translator knows
+    pub only_my_languages: bool,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { page: Vec<Entry> },
+}
+
+#[post("/api/post/translation-requests/queue")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_translate_posts() {
+        return Err(Error::Validation);
+    }
+
+    let page = Page::new(request.limit, request.offset);
+
+    let page = Entry::fetch_page(
+        &state.db_pool,
+        custom_claims.id,
+        request.only_my_languages,
+        page,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { page }))
+}
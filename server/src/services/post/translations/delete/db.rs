@@ -0,0 +1,121 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status as PostStatus;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+#[derive(Debug)]
+pub struct PostContent {
+    pub post_id: Id,
+    pub posted_by: Id,
+    pub source_post_content_id: Option<Id>,
+}
+
+impl PostContent {
+    pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                posts.id AS post_id,
+                posted_by,
+                source_post_content_id
+            FROM posts
+                JOIN post_contents ON posts.id = post_contents.post_id
+            WHERE
+                post_contents.id = ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
+            ",
+            post_content_id
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
+
+// Locks every content of the post, so concurrent deletes
+// wait for each other before counting what's left
+pub async fn lock_post_contents(tx: &mut Transaction<'_, MySql>, post_id: Id) -> Result<()> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM post_contents
+        WHERE post_id = ?
+        FOR UPDATE
+        ",
+        post_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    Ok(())
+}
+
+// `None` if it was deleted in the meantime
+pub async fn fetch_status(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+) -> Result<Option<String>> {
+    sqlx::query!(
+        "
+        SELECT
+            status
+        FROM post_contents
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        post_content_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.map(|row| row.status))
+}
+
+pub async fn count_other_approved(
+    tx: &mut Transaction<'_, MySql>,
+    post_id: Id,
+    post_content_id: Id,
+) -> Result<i64> {
+    sqlx::query!(
+        "
+        SELECT
+            COUNT(*) AS count
+        FROM post_contents
+        WHERE
+            post_id = ?
+            AND id != ?
+            AND status = ?
+            AND deleted_at IS NULL
+        ",
+        post_id,
+        post_content_id,
+        PostStatus::Approved.as_str()
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map(|row| row.count)
+}
+
+pub async fn delete_post_content(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    deleted_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE post_contents
+        SET
+            deleted_by = ?,
+            deleted_at = NOW()
+        WHERE
+            id = ?
+            AND deleted_at IS NULL
+        ",
+        deleted_by,
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await
+}
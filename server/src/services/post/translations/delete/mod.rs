@@ -0,0 +1,90 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status as PostStatus;
+use crate::db::post::RESTORE_WINDOW_DAYS;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{
+    count_other_approved, delete_post_content, fetch_status, lock_post_contents, PostContent,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    // Deleting the last approved content would leave an empty post,
+    // `/api/post/delete` should be used instead
+    LastContent,
+    // The original content can only go away together with the post
+    SourceContent,
+    Success { restorable_until: i64 },
+}
+
+#[post("/api/post/translations/delete")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_delete_posts() {
+        return Err(Error::Validation);
+    }
+
+    let post_content = match PostContent::fetch(&state.db_pool, request.post_content_id).await? {
+        Some(post_content) => post_content,
+        None => return Err(Error::Validation),
+    };
+
+    if post_content.posted_by != custom_claims.id && !custom_claims.role.can_moderate() {
+        return Err(Error::Validation);
+    }
+
+    if post_content.source_post_content_id == Some(request.post_content_id) {
+        return Ok(HttpResponse::Ok().json(Response::SourceContent));
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    lock_post_contents(&mut tx, post_content.post_id).await?;
+
+    let status = match fetch_status(&mut tx, request.post_content_id).await? {
+        Some(status) => status,
+        None => return Err(Error::Validation),
+    };
+
+    if status == PostStatus::Approved.as_str()
+        && count_other_approved(&mut tx, post_content.post_id, request.post_content_id).await? == 0
+    {
+        return Ok(HttpResponse::Ok().json(Response::LastContent));
+    }
+
+    delete_post_content(&mut tx, request.post_content_id, custom_claims.id).await?;
+
+    tx.commit().await?;
+
+    let restorable_until = (Utc::now() + Duration::days(RESTORE_WINDOW_DAYS)).timestamp_millis();
+
+    Ok(HttpResponse::Ok().json(Response::Success { restorable_until }))
+}
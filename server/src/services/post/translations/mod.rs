@@ -1 +1,7 @@
+pub mod delete;
+pub mod outdated;
+pub mod restore;
+pub mod reviews;
 pub mod status;
+pub mod suggestions;
+pub mod vote;
@@ -0,0 +1,42 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug)]
+pub struct TranslationSource {
+    pub posted_by: Id,
+    pub translated_by: Id,
+    pub source_revision_id: Option<Id>,
+    pub latest_source_revision_id: Option<Id>,
+}
+
+impl TranslationSource {
+    pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                posted_by,
+                translated_by,
+                source_revision_id,
+                (
+                    SELECT
+                        MAX(newer_revisions.id)
+                    FROM post_content_revisions AS newer_revisions
+                        JOIN post_content_revisions AS source_revisions ON newer_revisions.post_content_id = source_revisions.post_content_id
+                    WHERE
+                        source_revisions.id = post_contents.source_revision_id
+                ) AS `latest_source_revision_id?`
+            FROM posts
+                JOIN post_contents ON posts.id = post_contents.post_id
+            WHERE
+                post_contents.id = ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
+            ",
+            post_content_id
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
@@ -0,0 +1,90 @@
+use crate::db::id::Id;
+use crate::db::post::revision::Revision;
+use crate::error::Error;
+use crate::jwt;
+use crate::services::post::revisions::diff::{Diff, Mode};
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod resolve;
+
+mod db;
+use db::TranslationSource;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+    pub mode: Mode,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    UpToDate,
+    Outdated {
+        source_revision_id: Id,
+        latest_source_revision_id: Id,
+        diff: Diff,
+    },
+}
+
+// Shows what has changed in the source content
+// since the translation was made
+#[post("/api/post/translations/outdated")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let translation_source =
+        match TranslationSource::fetch(&state.db_pool, request.post_content_id).await? {
+            Some(translation_source) => translation_source,
+            None => return Err(Error::Validation),
+        };
+
+    if translation_source.posted_by != custom_claims.id
+        && translation_source.translated_by != custom_claims.id
+    {
+        return Err(Error::Validation);
+    }
+
+    let (source_revision_id, latest_source_revision_id) = match (
+        translation_source.source_revision_id,
+        translation_source.latest_source_revision_id,
+    ) {
+        (Some(source_revision_id), Some(latest_source_revision_id))
+            if latest_source_revision_id > source_revision_id =>
+        {
+            (source_revision_id, latest_source_revision_id)
+        }
+        _ => return Ok(HttpResponse::Ok().json(Response::UpToDate)),
+    };
+
+    let from = Revision::fetch_by_id(&state.db_pool, source_revision_id).await?;
+    let to = Revision::fetch_by_id(&state.db_pool, latest_source_revision_id).await?;
+
+    let diff = match (from, to) {
+        (Some(from), Some(to)) => Diff::compute(from, to, request.mode).await?,
+        _ => return Err(Error::Validation),
+    };
+
+    Ok(HttpResponse::Ok().json(Response::Outdated {
+        source_revision_id,
+        latest_source_revision_id,
+        diff,
+    }))
+}
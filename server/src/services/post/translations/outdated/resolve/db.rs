@@ -0,0 +1,54 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+#[derive(Debug)]
+pub struct Translation {
+    pub posted_by: Id,
+    pub translated_by: Id,
+}
+
+impl Translation {
+    pub async fn fetch(db_pool: &MySqlPool, post_content_id: Id) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                posted_by,
+                translated_by
+            FROM posts
+                JOIN post_contents ON posts.id = post_contents.post_id
+            WHERE
+                post_contents.id = ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
+            ",
+            post_content_id
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
+
+pub async fn mark_up_to_date(db_pool: &MySqlPool, post_content_id: Id) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE post_contents
+        SET
+            source_revision_id = (
+                SELECT
+                    MAX(newer_revisions.id)
+                FROM post_content_revisions AS newer_revisions
+                    JOIN post_content_revisions AS source_revisions ON newer_revisions.post_content_id = source_revisions.post_content_id
+                WHERE
+                    source_revisions.id = post_contents.source_revision_id
+            )
+        WHERE
+            id = ?
+            AND source_revision_id IS NOT NULL
+        ",
+        post_content_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,58 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{mark_up_to_date, Translation};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success,
+}
+
+// Marks a translation as caught up with the latest source revision
+#[post("/api/post/translations/outdated/resolve")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_translate_posts() {
+        return Err(Error::Validation);
+    }
+
+    let translation = match Translation::fetch(&state.db_pool, request.post_content_id).await? {
+        Some(translation) => translation,
+        None => return Err(Error::Validation),
+    };
+
+    if translation.posted_by != custom_claims.id && translation.translated_by != custom_claims.id {
+        return Err(Error::Validation);
+    }
+
+    mark_up_to_date(&state.db_pool, request.post_content_id).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
@@ -0,0 +1,55 @@
+use crate::db::id::Id;
+use crate::db::post::RESTORE_WINDOW_DAYS;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+#[derive(Debug)]
+pub struct DeletedPostContent {
+    pub posted_by: Id,
+    pub deleted_by: Option<Id>,
+}
+
+impl DeletedPostContent {
+    pub async fn fetch_restorable(
+        db_pool: &MySqlPool,
+        post_content_id: Id,
+    ) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                posted_by,
+                post_contents.deleted_by
+            FROM posts
+                JOIN post_contents ON posts.id = post_contents.post_id
+            WHERE
+                post_contents.id = ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at > NOW() - INTERVAL ? DAY
+            ",
+            post_content_id,
+            RESTORE_WINDOW_DAYS
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
+
+pub async fn restore_post_content(
+    db_pool: &MySqlPool,
+    post_content_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE post_contents
+        SET
+            deleted_by = NULL,
+            deleted_at = NULL
+        WHERE
+            id = ?
+        ",
+        post_content_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,61 @@
+use crate::db::comment::content::Content;
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn review_exists(db_pool: &MySqlPool, id: Id, post_content_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM translation_reviews
+        WHERE
+            id = ?
+            AND post_content_id = ?
+        ",
+        id,
+        post_content_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn insert_review(
+    db_pool: &MySqlPool,
+    post_content_id: Id,
+    paragraph: Option<u32>,
+    reply_to: Option<Id>,
+    content: &Content,
+    posted_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        INSERT INTO translation_reviews
+        (
+            post_content_id,
+            paragraph,
+            reply_to,
+            content,
+            posted_by,
+            posted_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        post_content_id,
+        paragraph,
+        reply_to,
+        content.as_ref(),
+        posted_by
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,90 @@
+use crate::db::comment::content::Content;
+use crate::db::id::Id;
+use crate::db::post::review::Target;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{insert_review, review_exists};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+    // `None` comments on the translation as a whole
+    pub paragraph: Option<u32>,
+    pub reply_to: Option<Id>,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Closed,
+    Success,
+}
+
+#[post("/api/post/translations/reviews/create")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_comment() {
+        return Err(Error::Validation);
+    }
+
+    let target = match Target::fetch(&state.db_pool, request.post_content_id).await? {
+        Some(target) => target,
+        None => return Err(Error::Validation),
+    };
+
+    if !target.is_participant(custom_claims.id) {
+        return Err(Error::Validation);
+    }
+
+    if !target.is_open() {
+        return Ok(HttpResponse::Ok().json(Response::Closed));
+    }
+
+    if let Some(paragraph) = request.paragraph {
+        if target.paragraph(paragraph).is_none() {
+            return Err(Error::Validation);
+        }
+    }
+
+    if let Some(reply_to) = request.reply_to {
+        if !review_exists(&state.db_pool, reply_to, request.post_content_id).await? {
+            return Err(Error::Validation);
+        }
+    }
+
+    let content = Content::parse(request.content)?;
+
+    insert_review(
+        &state.db_pool,
+        request.post_content_id,
+        request.paragraph,
+        request.reply_to,
+        &content,
+        custom_claims.id,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
@@ -0,0 +1,80 @@
+use crate::db::id::Id;
+use crate::db::user::meta::Meta as UserMeta;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct Review {
+    pub id: Id,
+    pub paragraph: Option<i32>,
+    pub reply_to: Option<Id>,
+    pub content: String,
+    pub posted_by: UserMeta,
+    pub posted_at: i64,
+}
+
+impl Review {
+    pub async fn fetch_all(db_pool: &MySqlPool, post_content_id: Id) -> Result<Vec<Self>> {
+        RawReview::fetch_all(db_pool, post_content_id)
+            .await
+            .map(|result| result.into_iter().map(|review| review.into()).collect())
+    }
+}
+
+impl From<RawReview> for Review {
+    fn from(raw: RawReview) -> Self {
+        Self {
+            id: raw.id,
+            paragraph: raw.paragraph,
+            reply_to: raw.reply_to,
+            content: raw.content,
+            posted_by: UserMeta {
+                id: raw.posted_by_id,
+                handle: raw.posted_by_handle,
+                profile_picture_file_name: raw.posted_by_profile_picture_file_name,
+            },
+            posted_at: raw.posted_at.timestamp_millis(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawReview {
+    id: Id,
+    paragraph: Option<i32>,
+    reply_to: Option<Id>,
+    content: String,
+    posted_by_id: Id,
+    posted_by_handle: String,
+    posted_by_profile_picture_file_name: Option<String>,
+    posted_at: NaiveDateTime,
+}
+
+impl RawReview {
+    async fn fetch_all(db_pool: &MySqlPool, post_content_id: Id) -> Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                translation_reviews.id,
+                translation_reviews.paragraph,
+                translation_reviews.reply_to,
+                translation_reviews.content,
+                users.id AS posted_by_id,
+                users.handle AS posted_by_handle,
+                CONCAT(files.id, \".\", files.extension) AS `posted_by_profile_picture_file_name?`,
+                translation_reviews.posted_at
+            FROM translation_reviews
+                JOIN users ON translation_reviews.posted_by = users.id
+                LEFT JOIN files ON users.profile_picture_file_id = files.id
+            WHERE translation_reviews.post_content_id = ?
+            ORDER BY translation_reviews.posted_at ASC, translation_reviews.id ASC
+            ",
+            post_content_id
+        )
+        .fetch_all(db_pool)
+        .await
+    }
+}
@@ -1,7 +1,7 @@
 use crate::db::id::Id;
 use crate::db::post::status::Status as PostStatus;
-use sqlx::mysql::{MySqlPool, MySqlQueryResult};
-use sqlx::Result;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
 
 pub async fn is_posted_by(db_pool: &MySqlPool, post_content_id: Id, user_id: Id) -> Result<bool> {
     sqlx::query!(
@@ -13,6 +13,8 @@ pub async fn is_posted_by(db_pool: &MySqlPool, post_content_id: Id, user_id: Id)
         WHERE
             post_contents.id = ?
             AND posted_by = ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
         ",
         post_content_id,
         user_id
@@ -22,68 +24,42 @@ pub async fn is_posted_by(db_pool: &MySqlPool, post_content_id: Id, user_id: Id)
     .map(|result| result.is_some())
 }
 
+// Several translations into the same language can be approved,
+// readers pick between them by voting
 pub async fn update_status(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     post_content_id: Id,
     status: PostStatus,
-) -> Result<()> {
-    reset_status(db_pool, post_content_id).await?;
-    set_status(db_pool, post_content_id, status).await?;
-
-    Ok(())
-}
-
-async fn reset_status(db_pool: &MySqlPool, post_content_id: Id) -> Result<MySqlQueryResult> {
+) -> Result<MySqlQueryResult> {
     sqlx::query!(
         "
         UPDATE post_contents
         SET
             status = ?
         WHERE
-            id IN (
-                SELECT
-                    id
-                FROM post_contents
-                WHERE
-                    post_id = (
-                        SELECT
-                            post_id
-                        FROM post_contents
-                        WHERE
-                            id = ?
-                    )
-                    AND language_id = (
-                        SELECT
-                            language_id
-                        FROM post_contents
-                        WHERE
-                            id = ?
-                    )
-            )
+            id = ?
         ",
-        PostStatus::Denied.as_str(),
+        status.as_str(),
         post_content_id,
-        post_content_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
-async fn set_status(
-    db_pool: &MySqlPool,
+
+pub async fn fetch_translated_by(
+    tx: &mut Transaction<'_, MySql>,
     post_content_id: Id,
-    status: PostStatus,
-) -> Result<MySqlQueryResult> {
+) -> Result<Option<Id>> {
     sqlx::query!(
         "
-        UPDATE post_contents
-        SET
-            status = ?
-        WHERE
-            id = ?
+        SELECT
+            translated_by
+        FROM post_contents
+        WHERE id = ?
         ",
-        status.as_str(),
-        post_content_id,
+        post_content_id
     )
-    .execute(db_pool)
+    .fetch_optional(&mut *tx)
     .await
+    .map(|result| result.map(|row| row.translated_by))
 }
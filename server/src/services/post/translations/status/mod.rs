@@ -1,7 +1,12 @@
 use crate::db::id::Id;
+use crate::db::notification::{kind::Kind as NotificationKind, notify};
 use crate::db::post::status::Status as PostStatus;
+use crate::db::post::translation_request::fulfill_translation_requests;
+use crate::db::translation_memory::update_translation_memory;
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
 use crate::error::Error;
 use crate::jwt;
+use crate::realtime::Event;
 use crate::State;
 use actix_web::{
     post,
@@ -11,7 +16,7 @@ use actix_web::{
 use serde::{Deserialize, Serialize};
 
 mod db;
-use db::{is_posted_by, update_status};
+use db::{fetch_translated_by, is_posted_by, update_status};
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
@@ -48,7 +53,64 @@ pub async fn service(
         return Err(Error::Validation);
     }
 
-    update_status(&state.db_pool, request.post_content_id, request.status).await?;
+    let mut tx = state.db_pool.begin().await?;
+
+    update_status(&mut tx, request.post_content_id, request.status).await?;
+
+    let mut events = Vec::new();
+
+    if matches!(request.status, PostStatus::Approved) {
+        events.extend(fulfill_translation_requests(&mut tx, request.post_content_id).await?);
+        update_translation_memory(&mut tx, request.post_content_id).await?;
+    }
+
+    let notification_kind = match request.status {
+        PostStatus::Pending => None,
+        PostStatus::Approved => Some(NotificationKind::TranslationApproved),
+        PostStatus::Denied => Some(NotificationKind::TranslationDenied),
+        PostStatus::ChangesRequested => Some(NotificationKind::ChangesRequested),
+    };
+
+    let webhook_event = match request.status {
+        PostStatus::Approved => Some(WebhookEvent::TranslationApproved),
+        PostStatus::Denied => Some(WebhookEvent::TranslationDenied),
+        PostStatus::Pending | PostStatus::ChangesRequested => None,
+    };
+
+    if let Some(webhook_event) = webhook_event {
+        webhook::enqueue(
+            &mut tx,
+            webhook_event,
+            WebhookSubject::PostContent(request.post_content_id),
+        )
+        .await?;
+    }
+
+    if let Some(translated_by) = fetch_translated_by(&mut tx, request.post_content_id).await? {
+        if let Some(notification_kind) = notification_kind {
+            let event = notify(
+                &mut tx,
+                translated_by,
+                notification_kind,
+                custom_claims.id,
+                Some(request.post_content_id),
+                None,
+            )
+            .await?;
+
+            events.extend(event);
+        }
+
+        events.push(Event::TranslationStatus {
+            post_content_id: request.post_content_id,
+            translated_by,
+            status: request.status,
+        });
+    }
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
 
     Ok(HttpResponse::Ok().json(Response::Success))
 }
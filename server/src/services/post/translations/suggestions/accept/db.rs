@@ -0,0 +1,100 @@
+use crate::db::id::Id;
+use crate::db::post::content::Content;
+use crate::db::post::suggestion::Status as SuggestionStatus;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+#[derive(Debug)]
+pub struct Suggestion {
+    pub post_content_id: Id,
+    pub paragraph: i32,
+    pub original: String,
+    pub content: String,
+    pub status: String,
+}
+
+impl Suggestion {
+    pub async fn fetch(db_pool: &MySqlPool, id: Id) -> Result<Option<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                post_content_id,
+                paragraph,
+                original,
+                content,
+                status
+            FROM translation_suggestions
+            WHERE id = ?
+            ",
+            id
+        )
+        .fetch_optional(db_pool)
+        .await
+    }
+}
+
+// Locks the row, so concurrent accepts on the same translation
+// apply their paragraphs one after another instead of overwriting each other
+pub async fn fetch_content_for_update(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+) -> Result<Option<String>> {
+    sqlx::query!(
+        "
+        SELECT
+            content
+        FROM post_contents
+        WHERE id = ?
+        FOR UPDATE
+        ",
+        post_content_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.map(|row| row.content))
+}
+
+pub async fn update_content(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    content: &Content,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE post_contents
+        SET
+            content = ?
+        WHERE id = ?
+        ",
+        content.as_ref(),
+        post_content_id
+    )
+    .execute(&mut *tx)
+    .await
+}
+
+pub async fn accept_suggestion(
+    tx: &mut Transaction<'_, MySql>,
+    id: Id,
+    resolved_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE translation_suggestions
+        SET
+            status = ?,
+            resolved_by = ?,
+            resolved_at = NOW()
+        WHERE
+            id = ?
+            AND status = ?
+        ",
+        SuggestionStatus::Accepted.as_str(),
+        resolved_by,
+        id,
+        SuggestionStatus::Pending.as_str()
+    )
+    .execute(&mut *tx)
+    .await
+}
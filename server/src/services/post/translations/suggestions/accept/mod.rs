@@ -0,0 +1,130 @@
+use crate::db::id::Id;
+use crate::db::mention::{update_mentions, Target as MentionTarget};
+use crate::db::post::content::Content;
+use crate::db::post::review::Target;
+use crate::db::post::revision::{insert_initial_revision, insert_revision};
+use crate::db::post::suggestion::Status as SuggestionStatus;
+use crate::db::translation_memory::refresh_translation_memory;
+use crate::db::webhook::{self, event::Event as WebhookEvent, Subject as WebhookSubject};
+use crate::error::Error;
+use crate::jwt;
+use crate::text::paragraph;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{accept_suggestion, fetch_content_for_update, update_content, Suggestion};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub suggestion_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Closed,
+    // The paragraph was changed after the suggestion was made
+    Conflict,
+    Success { revision_id: Id },
+}
+
+#[post("/api/post/translations/suggestions/accept")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_translate_posts() {
+        return Err(Error::Validation);
+    }
+
+    let suggestion = match Suggestion::fetch(&state.db_pool, request.suggestion_id).await? {
+        Some(suggestion) => suggestion,
+        None => return Err(Error::Validation),
+    };
+
+    if suggestion.status != SuggestionStatus::Pending.as_str() {
+        return Err(Error::Validation);
+    }
+
+    let target = match Target::fetch(&state.db_pool, suggestion.post_content_id).await? {
+        Some(target) => target,
+        None => return Err(Error::Validation),
+    };
+
+    if target.translated_by != custom_claims.id {
+        return Err(Error::Validation);
+    }
+
+    if !target.is_open() {
+        return Ok(HttpResponse::Ok().json(Response::Closed));
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    // Only one accept of the same suggestion gets past this
+    if accept_suggestion(&mut tx, request.suggestion_id, custom_claims.id)
+        .await?
+        .rows_affected()
+        == 0
+    {
+        return Ok(HttpResponse::Ok().json(Response::Conflict));
+    }
+
+    let current_content =
+        match fetch_content_for_update(&mut tx, suggestion.post_content_id).await? {
+            Some(current_content) => current_content,
+            None => return Err(Error::Validation),
+        };
+
+    let index = suggestion.paragraph as usize;
+
+    if paragraph::get(&current_content, index) != Some(suggestion.original.as_str()) {
+        return Ok(HttpResponse::Ok().json(Response::Conflict));
+    }
+
+    let content = match paragraph::replace(&current_content, index, &suggestion.content) {
+        Some(content) => Content::parse(content)?,
+        None => return Ok(HttpResponse::Ok().json(Response::Conflict)),
+    };
+
+    insert_initial_revision(&mut tx, suggestion.post_content_id).await?;
+    update_content(&mut tx, suggestion.post_content_id, &content).await?;
+    let revision_id =
+        insert_revision(&mut tx, suggestion.post_content_id, custom_claims.id).await?;
+    refresh_translation_memory(&mut tx, suggestion.post_content_id).await?;
+    let events = update_mentions(
+        &mut tx,
+        MentionTarget::PostContent(suggestion.post_content_id),
+        content.as_ref(),
+        custom_claims.id,
+    )
+    .await?;
+    webhook::enqueue(
+        &mut tx,
+        WebhookEvent::PostEdited,
+        WebhookSubject::PostContent(suggestion.post_content_id),
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
+
+    Ok(HttpResponse::Ok().json(Response::Success { revision_id }))
+}
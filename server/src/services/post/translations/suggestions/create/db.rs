@@ -0,0 +1,47 @@
+use crate::db::id::Id;
+use crate::db::post::content::Content;
+use crate::db::post::suggestion::Status as SuggestionStatus;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn insert_suggestion(
+    db_pool: &MySqlPool,
+    post_content_id: Id,
+    paragraph: u32,
+    original: &str,
+    content: &Content,
+    suggested_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        INSERT INTO translation_suggestions
+        (
+            post_content_id,
+            paragraph,
+            original,
+            content,
+            status,
+            suggested_by,
+            suggested_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        post_content_id,
+        paragraph,
+        original,
+        content.as_ref(),
+        SuggestionStatus::Pending.as_str(),
+        suggested_by
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,87 @@
+use crate::db::id::Id;
+use crate::db::post::content::Content;
+use crate::db::post::review::Target;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::insert_suggestion;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+    pub paragraph: u32,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Closed,
+    Success,
+}
+
+#[post("/api/post/translations/suggestions/create")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_manage_translations() {
+        return Err(Error::Validation);
+    }
+
+    let target = match Target::fetch(&state.db_pool, request.post_content_id).await? {
+        Some(target) => target,
+        None => return Err(Error::Validation),
+    };
+
+    // Suggestions come from the author of the post
+    // and are accepted or rejected by the translator
+    if target.posted_by != custom_claims.id {
+        return Err(Error::Validation);
+    }
+
+    if !target.is_open() {
+        return Ok(HttpResponse::Ok().json(Response::Closed));
+    }
+
+    let original = match target.paragraph(request.paragraph) {
+        Some(original) => original,
+        None => return Err(Error::Validation),
+    };
+
+    let content = Content::parse(request.content)?;
+
+    if content.as_ref() == original {
+        return Err(Error::Validation);
+    }
+
+    insert_suggestion(
+        &state.db_pool,
+        request.post_content_id,
+        request.paragraph,
+        original,
+        &content,
+        custom_claims.id,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
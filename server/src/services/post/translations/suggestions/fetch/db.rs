@@ -0,0 +1,97 @@
+use crate::db::id::Id;
+use crate::db::post::suggestion::Status as SuggestionStatus;
+use crate::db::user::meta::Meta as UserMeta;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct Suggestion {
+    pub id: Id,
+    pub paragraph: i32,
+    pub original: String,
+    pub content: String,
+    pub status: SuggestionStatus,
+    pub suggested_by: UserMeta,
+    pub suggested_at: i64,
+    pub resolved_at: Option<i64>,
+}
+
+impl Suggestion {
+    pub async fn fetch_all(db_pool: &MySqlPool, post_content_id: Id) -> Result<Vec<Self>> {
+        RawSuggestion::fetch_all(db_pool, post_content_id)
+            .await
+            .map(|result| {
+                result
+                    .into_iter()
+                    .map(|suggestion| suggestion.into())
+                    .collect()
+            })
+    }
+}
+
+impl From<RawSuggestion> for Suggestion {
+    fn from(raw: RawSuggestion) -> Self {
+        Self {
+            id: raw.id,
+            paragraph: raw.paragraph,
+            original: raw.original,
+            content: raw.content,
+            // TODO: Better error handling
+            status: SuggestionStatus::from_str(&raw.status).unwrap(),
+            suggested_by: UserMeta {
+                id: raw.suggested_by_id,
+                handle: raw.suggested_by_handle,
+                profile_picture_file_name: raw.suggested_by_profile_picture_file_name,
+            },
+            suggested_at: raw.suggested_at.timestamp_millis(),
+            resolved_at: raw
+                .resolved_at
+                .map(|resolved_at| resolved_at.timestamp_millis()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawSuggestion {
+    id: Id,
+    paragraph: i32,
+    original: String,
+    content: String,
+    status: String,
+    suggested_by_id: Id,
+    suggested_by_handle: String,
+    suggested_by_profile_picture_file_name: Option<String>,
+    suggested_at: NaiveDateTime,
+    resolved_at: Option<NaiveDateTime>,
+}
+
+impl RawSuggestion {
+    async fn fetch_all(db_pool: &MySqlPool, post_content_id: Id) -> Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                translation_suggestions.id,
+                translation_suggestions.paragraph,
+                translation_suggestions.original,
+                translation_suggestions.content,
+                translation_suggestions.status,
+                users.id AS suggested_by_id,
+                users.handle AS suggested_by_handle,
+                CONCAT(files.id, \".\", files.extension) AS `suggested_by_profile_picture_file_name?`,
+                translation_suggestions.suggested_at,
+                translation_suggestions.resolved_at
+            FROM translation_suggestions
+                JOIN users ON translation_suggestions.suggested_by = users.id
+                LEFT JOIN files ON users.profile_picture_file_id = files.id
+            WHERE translation_suggestions.post_content_id = ?
+            ORDER BY translation_suggestions.paragraph ASC, translation_suggestions.suggested_at ASC
+            ",
+            post_content_id
+        )
+        .fetch_all(db_pool)
+        .await
+    }
+}
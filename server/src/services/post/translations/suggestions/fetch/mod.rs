@@ -0,0 +1,54 @@
+use crate::db::id::Id;
+use crate::db::post::review::Target;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::Suggestion;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { suggestions: Vec<Suggestion> },
+}
+
+#[post("/api/post/translations/suggestions")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let target = match Target::fetch(&state.db_pool, request.post_content_id).await? {
+        Some(target) => target,
+        None => return Err(Error::Validation),
+    };
+
+    if !target.is_participant(custom_claims.id) {
+        return Err(Error::Validation);
+    }
+
+    let suggestions = Suggestion::fetch_all(&state.db_pool, request.post_content_id).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { suggestions }))
+}
@@ -0,0 +1,4 @@
+pub mod accept;
+pub mod create;
+pub mod fetch;
+pub mod reject;
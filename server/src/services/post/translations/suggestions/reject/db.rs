@@ -0,0 +1,47 @@
+use crate::db::id::Id;
+use crate::db::post::suggestion::Status as SuggestionStatus;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn fetch_pending_post_content_id(db_pool: &MySqlPool, id: Id) -> Result<Option<Id>> {
+    sqlx::query!(
+        "
+        SELECT
+            post_content_id
+        FROM translation_suggestions
+        WHERE
+            id = ?
+            AND status = ?
+        ",
+        id,
+        SuggestionStatus::Pending.as_str()
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.map(|row| row.post_content_id))
+}
+
+pub async fn reject_suggestion(
+    db_pool: &MySqlPool,
+    id: Id,
+    resolved_by: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE translation_suggestions
+        SET
+            status = ?,
+            resolved_by = ?,
+            resolved_at = NOW()
+        WHERE
+            id = ?
+            AND status = ?
+        ",
+        SuggestionStatus::Rejected.as_str(),
+        resolved_by,
+        id,
+        SuggestionStatus::Pending.as_str()
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,60 @@
+use crate::db::id::Id;
+use crate::db::post::review::Target;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{fetch_pending_post_content_id, reject_suggestion};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub suggestion_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success,
+}
+
+#[post("/api/post/translations/suggestions/reject")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let post_content_id =
+        match fetch_pending_post_content_id(&state.db_pool, request.suggestion_id).await? {
+            Some(post_content_id) => post_content_id,
+            None => return Err(Error::Validation),
+        };
+
+    let target = match Target::fetch(&state.db_pool, post_content_id).await? {
+        Some(target) => target,
+        None => return Err(Error::Validation),
+    };
+
+    if target.translated_by != custom_claims.id {
+        return Err(Error::Validation);
+    }
+
+    reject_suggestion(&state.db_pool, request.suggestion_id, custom_claims.id).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
@@ -0,0 +1,125 @@
+use crate::db::id::Id;
+use crate::db::post::status::Status as PostStatus;
+use sqlx::mysql::{MySql, MySqlPool, MySqlQueryResult};
+use sqlx::{Result, Transaction};
+
+pub async fn is_approved(db_pool: &MySqlPool, post_content_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            post_contents.id
+        FROM posts
+            JOIN post_contents ON posts.id = post_contents.post_id
+        WHERE
+            post_contents.id = ?
+            AND status = ?
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
+        ",
+        post_content_id,
+        PostStatus::Approved.as_str()
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn fetch_vote_count(db_pool: &MySqlPool, post_content_id: Id) -> Result<i64> {
+    sqlx::query!(
+        "
+        SELECT
+            COUNT(*) AS `vote_count!`
+        FROM translation_votes
+        WHERE post_content_id = ?
+        ",
+        post_content_id
+    )
+    .fetch_one(db_pool)
+    .await
+    .map(|row| row.vote_count)
+}
+
+// Returns whether the user has voted for the translation afterwards
+pub async fn update_vote(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    user_id: Id,
+) -> Result<bool> {
+    if vote_exists(tx, post_content_id, user_id).await? {
+        delete_vote(tx, post_content_id, user_id).await?;
+
+        Ok(false)
+    } else {
+        create_vote(tx, post_content_id, user_id).await?;
+
+        Ok(true)
+    }
+}
+
+async fn create_vote(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    user_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        INSERT INTO translation_votes
+        (
+            post_content_id,
+            voted_by,
+            voted_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        post_content_id,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await
+}
+
+async fn delete_vote(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    user_id: Id,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        DELETE FROM translation_votes
+        WHERE
+            post_content_id = ?
+            AND voted_by = ?
+        ",
+        post_content_id,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await
+}
+
+async fn vote_exists(
+    tx: &mut Transaction<'_, MySql>,
+    post_content_id: Id,
+    user_id: Id,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM translation_votes
+        WHERE
+            post_content_id = ?
+            AND voted_by = ?
+        ",
+        post_content_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.is_some())
+}
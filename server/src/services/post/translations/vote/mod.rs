@@ -0,0 +1,64 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{fetch_vote_count, is_approved, update_vote};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub post_content_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    NotFound,
+    Success { is_voted: bool, vote_count: i64 },
+}
+
+// Voting again takes the vote back
+#[post("/api/post/translations/vote")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_vote() {
+        return Err(Error::Validation);
+    }
+
+    // Only approved translations compete for the default spot
+    if !is_approved(&state.db_pool, request.post_content_id).await? {
+        return Ok(HttpResponse::Ok().json(Response::NotFound));
+    }
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let is_voted = update_vote(&mut tx, request.post_content_id, custom_claims.id).await?;
+
+    tx.commit().await?;
+
+    let vote_count = fetch_vote_count(&state.db_pool, request.post_content_id).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success {
+        is_voted,
+        vote_count,
+    }))
+}
@@ -0,0 +1,9 @@
+use crate::error::Error;
+use crate::State;
+use actix_web::{get, web::Data, HttpResponse};
+
+// The emojis that can be reacted with, configured by `$REACTIONS`
+#[get("/api/reactions")]
+pub async fn service(state: Data<State>) -> Result<HttpResponse, Error> {
+    Ok(HttpResponse::Ok().json(state.reactions.as_ref()))
+}
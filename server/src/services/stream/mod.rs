@@ -0,0 +1,89 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::realtime::Event;
+use crate::State;
+use actix_web::{
+    get,
+    web::{Bytes, Data, Query},
+    HttpResponse,
+};
+use futures::stream;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+// Keeps proxies from closing connections that are quiet for a while
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+// `EventSource` can't send a body, hence the query string.
+// The token is only checked when connecting
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: Option<String>,
+
+    // The post content the client is looking at, for comments and status changes
+    pub post_content_id: Option<Id>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+}
+
+struct Subscription {
+    receiver: Receiver<Event>,
+    user_id: Option<Id>,
+    post_content_id: Option<Id>,
+}
+
+#[get("/api/stream")]
+pub async fn service(
+    state: Data<State>,
+    Query(request): Query<Request>,
+) -> Result<HttpResponse, Error> {
+    let user_id = match jwt::get_fetcher_user_id(&state.jwt_private_key, &request.token) {
+        Ok(user_id) => user_id,
+        Err(_) => return Ok(HttpResponse::Ok().json(Response::Unauthorized)),
+    };
+
+    let subscription = Subscription {
+        receiver: state.broker.subscribe(),
+        user_id,
+        post_content_id: request.post_content_id,
+    };
+
+    let events = stream::unfold(subscription, |mut subscription| async move {
+        loop {
+            let received =
+                tokio::time::timeout(KEEP_ALIVE_INTERVAL, subscription.receiver.recv()).await;
+
+            let event = match received {
+                Ok(Ok(event)) => event,
+                // Missed events are gone, the client refetches on reconnect anyway
+                Ok(Err(RecvError::Lagged(_))) => continue,
+                Ok(Err(RecvError::Closed)) => return None,
+                Err(_) => {
+                    let comment = Bytes::from_static(b": keep-alive\n\n");
+
+                    return Some((Ok::<_, Error>(comment), subscription));
+                }
+            };
+
+            if !event.is_for(subscription.user_id, subscription.post_content_id) {
+                continue;
+            }
+
+            let data = serde_json::to_string(&event)
+                .expect("`Event` only holds ids and enums, it always serializes");
+
+            return Some((Ok(Bytes::from(format!("data: {data}\n\n"))), subscription));
+        }
+    });
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .streaming(events))
+}
@@ -0,0 +1,17 @@
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+pub async fn token_exists(db_pool: &MySqlPool, unsubscribe_token: &str) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM users
+        WHERE unsubscribe_token = ?
+        ",
+        unsubscribe_token
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
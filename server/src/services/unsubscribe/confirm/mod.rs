@@ -0,0 +1,49 @@
+use crate::error::Error;
+use crate::State;
+use actix_web::{
+    get,
+    web::{Data, Query},
+    HttpResponse,
+};
+use serde::Deserialize;
+
+mod db;
+use db::token_exists;
+
+// Link scanners and previews open every link in an email,
+// so opening the link only asks and the form does the unsubscribing
+const PAGE: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Unsubscribe from kotoba emails</title>
+</head>
+<body>
+<p>Stop getting notification emails and the weekly digest from kotoba?</p>
+<form method="post">
+<button type="submit">Unsubscribe</button>
+</form>
+</body>
+</html>
+"#;
+
+// Opened straight from the link in an email, so no JWT
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+}
+
+#[get("/api/unsubscribe")]
+pub async fn service(
+    state: Data<State>,
+    Query(request): Query<Request>,
+) -> Result<HttpResponse, Error> {
+    if !token_exists(&state.db_pool, &request.token).await? {
+        return Ok(HttpResponse::NotFound().body("Unknown unsubscribe link"));
+    }
+
+    // The form posts back to this very URL, token included
+    Ok(HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(PAGE))
+}
@@ -0,0 +1,48 @@
+use sqlx::mysql::MySql;
+use sqlx::{Result, Transaction};
+
+// Turns off the digest and every emailed notification kind,
+// in-app notifications stay as they are
+pub async fn unsubscribe(tx: &mut Transaction<'_, MySql>, unsubscribe_token: &str) -> Result<bool> {
+    let user_id = match sqlx::query!(
+        "
+        SELECT
+            id
+        FROM users
+        WHERE unsubscribe_token = ?
+        ",
+        unsubscribe_token
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    {
+        Some(row) => row.id,
+        None => return Ok(false),
+    };
+
+    sqlx::query!(
+        "
+        UPDATE users
+        SET
+            is_digest_enabled = FALSE
+        WHERE id = ?
+        ",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        UPDATE notification_preferences
+        SET
+            is_emailed = FALSE
+        WHERE user_id = ?
+        ",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(true)
+}
@@ -0,0 +1,39 @@
+use crate::error::Error;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Query},
+    HttpResponse,
+};
+use serde::Deserialize;
+
+pub mod confirm;
+
+mod db;
+use db::unsubscribe;
+
+// Submitted from the confirmation page, or straight from the mail client
+// as an RFC 8058 one-click unsubscribe. Either way the token is in the query
+// and there is no JWT, so the answer is plain text instead of a tagged response
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+}
+
+#[post("/api/unsubscribe")]
+pub async fn service(
+    state: Data<State>,
+    Query(request): Query<Request>,
+) -> Result<HttpResponse, Error> {
+    let mut tx = state.db_pool.begin().await?;
+
+    let is_unsubscribed = unsubscribe(&mut tx, &request.token).await?;
+
+    tx.commit().await?;
+
+    if !is_unsubscribed {
+        return Ok(HttpResponse::NotFound().body("Unknown unsubscribe link"));
+    }
+
+    Ok(HttpResponse::Ok().body("You won't get any more emails from kotoba"))
+}
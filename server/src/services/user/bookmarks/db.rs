@@ -28,7 +28,9 @@ pub async fn is_bookmarks_private(db_pool: &MySqlPool, user_id: Id) -> Result<Op
 
 pub async fn fetch_page(
     db_pool: &MySqlPool,
+    fetcher_user_id: Option<Id>,
     fetcher_language_ids: &Vec<Id>,
+    prefers_source_language: bool,
     user_id: Id,
     query: &str,
     page: Page,
@@ -49,6 +51,8 @@ pub async fn fetch_page(
             WHERE status = ?
                 AND posts.id IN (SELECT post_id FROM bookmarks WHERE user_id = ?)
                 AND (title LIKE ? OR post_contents.description LIKE ? OR content LIKE ?)
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
             ORDER BY translated_at DESC
             LIMIT ? OFFSET ?
         ) AS filtered_posts
@@ -67,9 +71,14 @@ pub async fn fetch_page(
     .map(|row| row.id)
     .collect();
 
-    let page =
-        PostMeta::fetch_from_post_ids_with_best_language(db_pool, fetcher_language_ids, &post_ids)
-            .await?;
+    let page = PostMeta::fetch_from_post_ids_with_best_language(
+        db_pool,
+        fetcher_user_id,
+        fetcher_language_ids,
+        prefers_source_language,
+        &post_ids,
+    )
+    .await?;
 
     Ok(page)
 }
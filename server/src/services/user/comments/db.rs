@@ -6,18 +6,19 @@ use sqlx::Result;
 
 pub async fn fetch_page(
     db_pool: &MySqlPool,
+    fetcher_user_id: Option<Id>,
     user_id: Id,
     query: &str,
     page: Page,
 ) -> Result<Vec<Comment>> {
     let page = fetch_raw_page(db_pool, user_id, query, page).await?;
 
-    let page = page
-        .into_iter()
-        .map(|raw_comment| raw_comment.into())
-        .collect();
+    let mut comments = Vec::new();
+    for raw_comment in page {
+        comments.push(Comment::fetch_from_raw(db_pool, fetcher_user_id, raw_comment).await?);
+    }
 
-    Ok(page)
+    Ok(comments)
 }
 
 async fn fetch_raw_page(
@@ -34,6 +35,8 @@ async fn fetch_raw_page(
         SELECT
             comments.id,
             comments.post_content_id,
+            post_contents.language_id,
+            languages.name AS language,
             comments.reply_to as reply_to_id,
             reply_to_users.id as reply_to_user_id,
             reply_to_users.handle as reply_to_handle,
@@ -42,14 +45,22 @@ async fn fetch_raw_page(
             posted_by_users.id as posted_by_id,
             posted_by_users.handle as posted_by_handle,
             CONCAT(posted_by_files.id, \".\", posted_by_files.extension) AS posted_by_profile_picture_file_name,
-            comments.posted_at
+            comments.posted_at,
+            comments.edited_at,
+            comments.deleted_at
         FROM comments
+            JOIN post_contents ON comments.post_content_id = post_contents.id
+            JOIN posts ON post_contents.post_id = posts.id
+            JOIN languages ON post_contents.language_id = languages.id
             LEFT JOIN comments AS reply_to ON comments.reply_to = reply_to.id 
             LEFT JOIN users AS reply_to_users ON reply_to.posted_by = reply_to_users.id
             LEFT JOIN files AS reply_to_files ON reply_to_users.profile_picture_file_id = reply_to_files.id
             JOIN users AS posted_by_users ON comments.posted_by = posted_by_users.id
             LEFT JOIN files AS posted_by_files ON posted_by_users.profile_picture_file_id = posted_by_files.id
         WHERE (comments.content LIKE ? OR reply_to_users.handle LIKE ?) AND comments.posted_by = ?
+            AND comments.deleted_at IS NULL
+            AND posts.deleted_at IS NULL
+            AND post_contents.deleted_at IS NULL
         LIMIT ? OFFSET ?
         ",
         query,
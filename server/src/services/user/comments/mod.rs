@@ -1,6 +1,7 @@
 use crate::db::id::Id;
 use crate::db::page::Page;
 use crate::error::Error;
+use crate::jwt;
 use crate::State;
 use actix_web::{
     post,
@@ -14,6 +15,8 @@ use db::fetch_page;
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
+    pub token: Option<String>,
+
     pub user_id: Id,
     pub query: String,
     pub limit: u64,
@@ -26,8 +29,16 @@ pub async fn service(
     Json(request): Json<Request>,
 ) -> Result<HttpResponse, Error> {
     let page = Page::new(request.limit, request.offset);
+    let fetcher_user_id = jwt::get_fetcher_user_id(&state.jwt_private_key, &request.token)?;
 
-    let page = fetch_page(&state.db_pool, request.user_id, &request.query, page).await?;
+    let page = fetch_page(
+        &state.db_pool,
+        fetcher_user_id,
+        request.user_id,
+        &request.query,
+        page,
+    )
+    .await?;
 
     Ok(HttpResponse::Ok().json(page))
 }
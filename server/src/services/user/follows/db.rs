@@ -1,21 +1,27 @@
 use crate::db::id::Id;
-use sqlx::mysql::{MySqlPool, MySqlQueryResult};
-use sqlx::Result;
+use crate::db::notification::kind::Kind as NotificationKind;
+use sqlx::mysql::{MySql, MySqlQueryResult};
+use sqlx::{Result, Transaction};
 
+// Returns whether the follower follows the user afterwards
 pub async fn update_follow(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     follower_user_id: Id,
     followed_user_id: Id,
-) -> Result<MySqlQueryResult> {
-    if follow_exists(db_pool, follower_user_id, followed_user_id).await? {
-        delete_follow(db_pool, follower_user_id, followed_user_id).await
+) -> Result<bool> {
+    if follow_exists(tx, follower_user_id, followed_user_id).await? {
+        delete_follow(tx, follower_user_id, followed_user_id).await?;
+
+        Ok(false)
     } else {
-        create_follow(db_pool, follower_user_id, followed_user_id).await
+        create_follow(tx, follower_user_id, followed_user_id).await?;
+
+        Ok(true)
     }
 }
 
 async fn create_follow(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     follower_user_id: Id,
     followed_user_id: Id,
 ) -> Result<MySqlQueryResult> {
@@ -35,12 +41,12 @@ async fn create_follow(
         follower_user_id,
         followed_user_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
 
 async fn delete_follow(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     follower_user_id: Id,
     followed_user_id: Id,
 ) -> Result<MySqlQueryResult> {
@@ -52,12 +58,12 @@ async fn delete_follow(
         follower_user_id,
         followed_user_id
     )
-    .execute(db_pool)
+    .execute(&mut *tx)
     .await
 }
 
 async fn follow_exists(
-    db_pool: &MySqlPool,
+    tx: &mut Transaction<'_, MySql>,
     follower_user_id: Id,
     followed_user_id: Id,
 ) -> Result<bool> {
@@ -71,7 +77,33 @@ async fn follow_exists(
         follower_user_id,
         followed_user_id
     )
-    .fetch_optional(db_pool)
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.is_some())
+}
+
+// Following someone again after unfollowing them doesn't notify them again
+pub async fn has_notified_follow(
+    tx: &mut Transaction<'_, MySql>,
+    follower_user_id: Id,
+    followed_user_id: Id,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM notifications
+        WHERE
+            user_id = ?
+            AND kind = ?
+            AND actor_id = ?
+        LIMIT 1
+        ",
+        followed_user_id,
+        NotificationKind::Follow.as_str(),
+        follower_user_id
+    )
+    .fetch_optional(&mut *tx)
     .await
     .map(|result| result.is_some())
 }
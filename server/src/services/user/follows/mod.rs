@@ -1,4 +1,5 @@
 use crate::db::id::Id;
+use crate::db::notification::{kind::Kind as NotificationKind, notify};
 use crate::error::Error;
 use crate::jwt;
 use crate::State;
@@ -12,7 +13,7 @@ use serde::{Deserialize, Serialize};
 pub mod fetch;
 
 mod db;
-use db::update_follow;
+use db::{has_notified_follow, update_follow};
 
 #[derive(Debug, Deserialize)]
 pub struct Request {
@@ -45,7 +46,29 @@ pub async fn service(
         return Ok(HttpResponse::Ok().json(Response::InvalidPermissions));
     }
 
-    update_follow(&state.db_pool, custom_claims.id, request.user_id).await?;
+    let mut tx = state.db_pool.begin().await?;
+
+    let is_following = update_follow(&mut tx, custom_claims.id, request.user_id).await?;
+
+    let mut events = Vec::new();
+
+    if is_following && !has_notified_follow(&mut tx, custom_claims.id, request.user_id).await? {
+        let event = notify(
+            &mut tx,
+            request.user_id,
+            NotificationKind::Follow,
+            custom_claims.id,
+            None,
+            None,
+        )
+        .await?;
+
+        events.extend(event);
+    }
+
+    tx.commit().await?;
+
+    state.broker.publish(events).await;
 
     Ok(HttpResponse::Ok().json(Response::Success))
 }
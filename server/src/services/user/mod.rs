@@ -3,5 +3,7 @@ pub mod comments;
 pub mod fetch;
 pub mod follows;
 pub mod posts;
+pub mod preferences;
 pub mod search;
+pub mod translation_requests;
 pub mod translations;
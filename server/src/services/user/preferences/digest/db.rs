@@ -0,0 +1,22 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn update_is_digest_enabled(
+    db_pool: &MySqlPool,
+    user_id: Id,
+    is_digest_enabled: bool,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE users
+        SET
+            is_digest_enabled = ?
+        WHERE id = ?
+        ",
+        is_digest_enabled,
+        user_id
+    )
+    .execute(db_pool)
+    .await
+}
@@ -0,0 +1,22 @@
+use crate::db::id::Id;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn update_prefers_source_language(
+    db_pool: &MySqlPool,
+    user_id: Id,
+    prefers_source_language: bool,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        UPDATE users
+        SET
+            prefers_source_language = ?
+        WHERE id = ?
+        ",
+        prefers_source_language,
+        user_id
+    )
+    .execute(db_pool)
+    .await
+}
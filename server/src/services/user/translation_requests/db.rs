@@ -0,0 +1,93 @@
+use crate::db::id::Id;
+use crate::db::page::Page;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct TranslationRequest {
+    pub id: Id,
+    pub post_id: Id,
+    pub language_id: Id,
+    pub language: String,
+    pub requested_at: i64,
+    // The approved translation that fulfilled the request
+    pub post_content_id: Option<Id>,
+    pub fulfilled_at: Option<i64>,
+}
+
+impl TranslationRequest {
+    pub async fn fetch_page(
+        db_pool: &MySqlPool,
+        fetcher_user_id: Id,
+        page: Page,
+    ) -> Result<Vec<Self>> {
+        RawTranslationRequest::fetch_page(db_pool, fetcher_user_id, page)
+            .await
+            .map(|result| {
+                result
+                    .into_iter()
+                    .map(|translation_request| translation_request.into())
+                    .collect()
+            })
+    }
+}
+
+impl From<RawTranslationRequest> for TranslationRequest {
+    fn from(raw: RawTranslationRequest) -> Self {
+        Self {
+            id: raw.id,
+            post_id: raw.post_id,
+            language_id: raw.language_id,
+            language: raw.language,
+            requested_at: raw.requested_at.timestamp_millis(),
+            post_content_id: raw.post_content_id,
+            fulfilled_at: raw
+                .fulfilled_at
+                .map(|fulfilled_at| fulfilled_at.timestamp_millis()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawTranslationRequest {
+    id: Id,
+    post_id: Id,
+    language_id: Id,
+    language: String,
+    requested_at: NaiveDateTime,
+    post_content_id: Option<Id>,
+    fulfilled_at: Option<NaiveDateTime>,
+}
+
+impl RawTranslationRequest {
+    async fn fetch_page(db_pool: &MySqlPool, fetcher_user_id: Id, page: Page) -> Result<Vec<Self>> {
+        sqlx::query_as!(
+            Self,
+            "
+            SELECT
+                translation_requests.id,
+                translation_requests.post_id,
+                translation_requests.language_id,
+                languages.name AS language,
+                translation_requests.requested_at,
+                translation_requests.post_content_id,
+                translation_requests.fulfilled_at
+            FROM translation_requests
+                JOIN languages ON translation_requests.language_id = languages.id
+                JOIN posts ON translation_requests.post_id = posts.id
+            WHERE
+                translation_requests.requested_by = ?
+                AND posts.deleted_at IS NULL
+            ORDER BY translation_requests.requested_at DESC
+            LIMIT ? OFFSET ?
+            ",
+            fetcher_user_id,
+            page.get_limit(),
+            page.get_offset()
+        )
+        .fetch_all(db_pool)
+        .await
+    }
+}
@@ -15,6 +15,8 @@ pub struct Translation {
     pub language: String,
     pub title: String,
     pub status: Status,
+    pub is_outdated: bool,
+    pub is_machine_translated: bool,
 
     pub posted_by: UserMeta,
     pub translated_by: UserMeta,
@@ -52,6 +54,8 @@ impl From<RawTranslation> for Translation {
             title: raw.title,
             // TODO: Better error handling
             status: Status::from_str(&raw.status).unwrap(),
+            is_outdated: raw.newer_source_revision_count != 0,
+            is_machine_translated: raw.is_machine_translated != 0,
 
             posted_by: UserMeta {
                 id: raw.posted_by_id,
@@ -78,6 +82,8 @@ pub struct RawTranslation {
     pub language: String,
     pub title: String,
     pub status: String,
+    pub newer_source_revision_count: i64,
+    pub is_machine_translated: i8,
 
     pub posted_by_id: Id,
     pub posted_by_handle: String,
@@ -124,6 +130,8 @@ impl RawTranslation {
                 languages.name AS language,
                 title,
                 status,
+                COALESCE(newer_source_revision_counts.newer_source_revision_count, 0) AS `newer_source_revision_count!`,
+                is_machine_translated,
                 users_posted_by.id AS posted_by_id,
                 users_posted_by.handle AS posted_by_handle,
                 CONCAT(files_posted_by.id, \".\", files_posted_by.extension) AS posted_by_profile_picture_file_name,
@@ -134,6 +142,7 @@ impl RawTranslation {
                 translated_at
             FROM posts
                 JOIN post_contents ON posts.id = post_contents.post_id
+                LEFT JOIN newer_source_revision_counts ON post_contents.id = newer_source_revision_counts.post_content_id
                 JOIN languages ON post_contents.language_id = languages.id
                 JOIN users AS users_posted_by ON posts.posted_by = users_posted_by.id
                 LEFT JOIN files AS files_posted_by ON users_posted_by.profile_picture_file_id = files_posted_by.id
@@ -142,6 +151,8 @@ impl RawTranslation {
             WHERE
                 translated_by = ?
                 AND title LIKE ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
             ORDER BY translated_at DESC
             LIMIT ? OFFSET ?
             ",
@@ -172,6 +183,8 @@ impl RawTranslation {
                 languages.name AS language,
                 title,
                 status,
+                COALESCE(newer_source_revision_counts.newer_source_revision_count, 0) AS `newer_source_revision_count!`,
+                is_machine_translated,
                 users_posted_by.id AS posted_by_id,
                 users_posted_by.handle AS posted_by_handle,
                 CONCAT(files_posted_by.id, \".\", files_posted_by.extension) AS posted_by_profile_picture_file_name,
@@ -182,6 +195,7 @@ impl RawTranslation {
                 translated_at
             FROM posts
                 JOIN post_contents ON posts.id = post_contents.post_id
+                LEFT JOIN newer_source_revision_counts ON post_contents.id = newer_source_revision_counts.post_content_id
                 JOIN languages ON post_contents.language_id = languages.id
                 JOIN users AS users_posted_by ON posts.posted_by = users_posted_by.id
                 LEFT JOIN files AS files_posted_by ON users_posted_by.profile_picture_file_id = files_posted_by.id
@@ -191,6 +205,8 @@ impl RawTranslation {
                 posted_by = ?
                 AND translated_by != ?
                 AND title LIKE ?
+                AND posts.deleted_at IS NULL
+                AND post_contents.deleted_at IS NULL
             LIMIT ? OFFSET ?
             ",
             fetcher_user_id,
@@ -0,0 +1,44 @@
+use crate::db::id::Id;
+use crate::db::webhook::event::Event;
+use crate::db::webhook::url::Url;
+use sqlx::mysql::{MySqlPool, MySqlQueryResult};
+use sqlx::Result;
+
+pub async fn insert_webhook(
+    db_pool: &MySqlPool,
+    user_id: Id,
+    url: &Url,
+    secret: &str,
+    events: &[Event],
+    is_global: bool,
+) -> Result<MySqlQueryResult> {
+    sqlx::query!(
+        "
+        INSERT INTO webhooks
+        (
+            user_id,
+            url,
+            secret,
+            events,
+            is_global,
+            created_at
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            NOW()
+        )
+        ",
+        user_id,
+        url.as_ref(),
+        secret,
+        Event::format_set(events),
+        is_global
+    )
+    .execute(db_pool)
+    .await
+}
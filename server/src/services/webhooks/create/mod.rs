@@ -0,0 +1,77 @@
+use crate::db::id::Id;
+use crate::db::webhook::event::Event;
+use crate::db::webhook::url::{resolve_public, Url};
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+mod db;
+use db::insert_webhook;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub url: String,
+    pub events: Vec<Event>,
+    #[serde(default)]
+    pub is_global: bool,
+}
+
+// The secret is only ever shown here
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    Success { id: Id, secret: String },
+}
+
+#[post("/api/webhooks/create")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !custom_claims.role.can_register_webhooks() {
+        return Err(Error::Validation);
+    }
+
+    if request.is_global && !custom_claims.role.can_register_global_webhooks() {
+        return Err(Error::Validation);
+    }
+
+    let url = Url::parse(request.url)?;
+    resolve_public(url.as_ref()).await?;
+
+    if request.events.is_empty() || request.events.contains(&Event::Ping) {
+        return Err(Error::Validation);
+    }
+
+    let secret = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+    let id = insert_webhook(
+        &state.db_pool,
+        custom_claims.id,
+        &url,
+        &secret,
+        &request.events,
+        request.is_global,
+    )
+    .await?
+    .last_insert_id() as Id;
+
+    Ok(HttpResponse::Ok().json(Response::Success { id, secret }))
+}
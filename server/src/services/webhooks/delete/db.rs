@@ -0,0 +1,50 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySql;
+use sqlx::{Result, Transaction};
+
+// The delivery log goes along with the webhook
+pub async fn delete_webhook(
+    tx: &mut Transaction<'_, MySql>,
+    webhook_id: Id,
+    user_id: Id,
+) -> Result<bool> {
+    let is_owned = sqlx::query!(
+        "
+        SELECT
+            id
+        FROM webhooks
+        WHERE id = ? AND user_id = ?
+        ",
+        webhook_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .is_some();
+
+    if !is_owned {
+        return Ok(false);
+    }
+
+    sqlx::query!(
+        "
+        DELETE FROM webhook_deliveries
+        WHERE webhook_id = ?
+        ",
+        webhook_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "
+        DELETE FROM webhooks
+        WHERE id = ?
+        ",
+        webhook_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    Ok(true)
+}
@@ -0,0 +1,53 @@
+use crate::db::id::Id;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::delete_webhook;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub webhook_id: Id,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    NotFound,
+    Success,
+}
+
+#[post("/api/webhooks/delete")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let is_deleted = delete_webhook(&mut tx, request.webhook_id, custom_claims.id).await?;
+
+    tx.commit().await?;
+
+    if !is_deleted {
+        return Ok(HttpResponse::Ok().json(Response::NotFound));
+    }
+
+    Ok(HttpResponse::Ok().json(Response::Success))
+}
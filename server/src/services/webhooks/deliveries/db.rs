@@ -0,0 +1,90 @@
+use crate::db::id::Id;
+use crate::db::page::Page;
+use crate::db::webhook::event::Event;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct Delivery {
+    pub id: Id,
+    pub event: Event,
+    pub attempt_count: i32,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<i64>,
+    pub next_attempt_at: Option<i64>,
+    pub created_at: i64,
+}
+
+impl From<RawDelivery> for Delivery {
+    fn from(raw: RawDelivery) -> Self {
+        Self {
+            id: raw.id,
+            // TODO: Better error handling
+            event: Event::from_str(&raw.event).unwrap(),
+            attempt_count: raw.attempt_count,
+            last_status_code: raw.last_status_code,
+            last_error: raw.last_error,
+            delivered_at: raw.delivered_at.map(|at| at.timestamp_millis()),
+            next_attempt_at: raw.next_attempt_at.map(|at| at.timestamp_millis()),
+            created_at: raw.created_at.timestamp_millis(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawDelivery {
+    id: Id,
+    event: String,
+    attempt_count: i32,
+    last_status_code: Option<i32>,
+    last_error: Option<String>,
+    delivered_at: Option<NaiveDateTime>,
+    next_attempt_at: Option<NaiveDateTime>,
+    created_at: NaiveDateTime,
+}
+
+pub async fn is_owner(db_pool: &MySqlPool, webhook_id: Id, user_id: Id) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM webhooks
+        WHERE id = ? AND user_id = ?
+        ",
+        webhook_id,
+        user_id
+    )
+    .fetch_optional(db_pool)
+    .await
+    .map(|result| result.is_some())
+}
+
+pub async fn fetch_page(db_pool: &MySqlPool, webhook_id: Id, page: Page) -> Result<Vec<Delivery>> {
+    sqlx::query_as!(
+        RawDelivery,
+        "
+        SELECT
+            id,
+            event,
+            attempt_count,
+            last_status_code,
+            last_error,
+            delivered_at,
+            next_attempt_at,
+            created_at
+        FROM webhook_deliveries
+        WHERE webhook_id = ?
+        ORDER BY created_at DESC, id DESC
+        LIMIT ? OFFSET ?
+        ",
+        webhook_id,
+        page.get_limit(),
+        page.get_offset()
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|result| result.into_iter().map(|delivery| delivery.into()).collect())
+}
@@ -0,0 +1,54 @@
+use crate::db::id::Id;
+use crate::db::page::Page;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::{fetch_page, is_owner, Delivery};
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub webhook_id: Id,
+    pub limit: u64,
+    pub offset: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    NotFound,
+    Success { page: Vec<Delivery> },
+}
+
+#[post("/api/webhooks/deliveries")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    if !is_owner(&state.db_pool, request.webhook_id, custom_claims.id).await? {
+        return Ok(HttpResponse::Ok().json(Response::NotFound));
+    }
+
+    let page = Page::new(request.limit, request.offset);
+
+    let page = fetch_page(&state.db_pool, request.webhook_id, page).await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { page }))
+}
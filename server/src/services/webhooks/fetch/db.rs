@@ -0,0 +1,57 @@
+use crate::db::id::Id;
+use crate::db::webhook::event::Event;
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::mysql::MySqlPool;
+use sqlx::Result;
+
+#[derive(Debug, Serialize)]
+pub struct Webhook {
+    pub id: Id,
+    pub url: String,
+    pub events: Vec<Event>,
+    pub is_global: bool,
+    pub created_at: i64,
+}
+
+impl From<RawWebhook> for Webhook {
+    fn from(raw: RawWebhook) -> Self {
+        Self {
+            id: raw.id,
+            url: raw.url,
+            events: Event::parse_set(&raw.events),
+            is_global: raw.is_global != 0,
+            created_at: raw.created_at.timestamp_millis(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RawWebhook {
+    id: Id,
+    url: String,
+    events: String,
+    is_global: i8,
+    created_at: NaiveDateTime,
+}
+
+pub async fn fetch_webhooks(db_pool: &MySqlPool, user_id: Id) -> Result<Vec<Webhook>> {
+    sqlx::query_as!(
+        RawWebhook,
+        "
+        SELECT
+            id,
+            url,
+            events,
+            is_global,
+            created_at
+        FROM webhooks
+        WHERE user_id = ?
+        ORDER BY created_at DESC
+        ",
+        user_id
+    )
+    .fetch_all(db_pool)
+    .await
+    .map(|result| result.into_iter().map(|webhook| webhook.into()).collect())
+}
@@ -0,0 +1,23 @@
+use crate::db::id::Id;
+use sqlx::mysql::MySql;
+use sqlx::{Result, Transaction};
+
+pub async fn is_owner(
+    tx: &mut Transaction<'_, MySql>,
+    webhook_id: Id,
+    user_id: Id,
+) -> Result<bool> {
+    sqlx::query!(
+        "
+        SELECT
+            id
+        FROM webhooks
+        WHERE id = ? AND user_id = ?
+        ",
+        webhook_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map(|result| result.is_some())
+}
@@ -0,0 +1,55 @@
+use crate::db::id::Id;
+use crate::db::webhook::enqueue_ping;
+use crate::error::Error;
+use crate::jwt;
+use crate::State;
+use actix_web::{
+    post,
+    web::{Data, Json},
+    HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+
+mod db;
+use db::is_owner;
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub token: String,
+
+    pub webhook_id: Id,
+}
+
+// The ping is picked up by the webhooks job like any other delivery
+#[derive(Debug, Serialize)]
+#[serde(tag = "tag")]
+pub enum Response {
+    Unauthorized,
+    NotFound,
+    Success { delivery_id: Id },
+}
+
+#[post("/api/webhooks/test")]
+pub async fn service(
+    state: Data<State>,
+    Json(request): Json<Request>,
+) -> Result<HttpResponse, Error> {
+    let custom_claims = match jwt::auth(&state.jwt_private_key, &request.token) {
+        Ok(custom_claims) => custom_claims,
+        Err(_) => {
+            return Ok(HttpResponse::Ok().json(Response::Unauthorized));
+        }
+    };
+
+    let mut tx = state.db_pool.begin().await?;
+
+    if !is_owner(&mut tx, request.webhook_id, custom_claims.id).await? {
+        return Ok(HttpResponse::Ok().json(Response::NotFound));
+    }
+
+    let delivery_id = enqueue_ping(&mut tx, request.webhook_id).await?;
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(Response::Success { delivery_id }))
+}
@@ -0,0 +1,236 @@
+use std::ops::Range;
+
+// Length based alignment in the spirit of Gale & Church (1993):
+// segments of a text and of its translation are matched up so that
+// the lengths of matched segments are as proportional as possible.
+// Besides 1-1 matches a segment can be merged with its neighbour
+// (2-1, 1-2, 2-2) or have no counterpart at all (1-0, 0-1)
+
+#[derive(Debug, Clone)]
+pub struct Group {
+    pub source: Range<usize>,
+    pub target: Range<usize>,
+    // How well the lengths agree, from `0.0` to `1.0`.
+    // Segments without a counterpart always get `0.0`
+    pub confidence: f64,
+}
+
+// (source segments, target segments, prior probability)
+const BEADS: [(usize, usize, f64); 6] = [
+    (1, 1, 0.89),
+    (1, 0, 0.0099 / 2.0),
+    (0, 1, 0.0099 / 2.0),
+    (2, 1, 0.089 / 2.0),
+    (1, 2, 0.089 / 2.0),
+    (2, 2, 0.011),
+];
+
+// Variance of the target length per source character
+const VARIANCE: f64 = 6.8;
+
+pub fn align<S: AsRef<str>>(source: &[S], target: &[S]) -> Vec<Group> {
+    let source_lens: Vec<f64> = source.iter().map(|s| char_count(s.as_ref())).collect();
+    let target_lens: Vec<f64> = target.iter().map(|s| char_count(s.as_ref())).collect();
+
+    let source_total: f64 = source_lens.iter().sum();
+    let target_total: f64 = target_lens.iter().sum();
+
+    // Expected amount of target characters per source character
+    let ratio = if source_total > 0.0 && target_total > 0.0 {
+        target_total / source_total
+    } else {
+        1.0
+    };
+
+    let (n, m) = (source.len(), target.len());
+
+    // `costs[i][j]` is the cost of aligning the first `i` source
+    // segments with the first `j` target segments
+    let mut costs = vec![vec![f64::INFINITY; m + 1]; n + 1];
+    let mut moves = vec![vec![(0, 0); m + 1]; n + 1];
+    costs[0][0] = 0.0;
+
+    for i in 0..=n {
+        for j in 0..=m {
+            if i == 0 && j == 0 {
+                continue;
+            }
+
+            for (di, dj, prior) in BEADS {
+                if di > i || dj > j || costs[i - di][j - dj].is_infinite() {
+                    continue;
+                }
+
+                let source_len: f64 = source_lens[i - di..i].iter().sum();
+                let target_len: f64 = target_lens[j - dj..j].iter().sum();
+
+                let cost = costs[i - di][j - dj]
+                    - (prior * match_probability(source_len, target_len, ratio)).ln();
+
+                if cost < costs[i][j] {
+                    costs[i][j] = cost;
+                    moves[i][j] = (di, dj);
+                }
+            }
+        }
+    }
+
+    let mut groups = Vec::new();
+
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        let (di, dj) = moves[i][j];
+
+        let confidence = if di == 0 || dj == 0 {
+            0.0
+        } else {
+            let source_len: f64 = source_lens[i - di..i].iter().sum();
+            let target_len: f64 = target_lens[j - dj..j].iter().sum();
+
+            match_probability(source_len, target_len, ratio)
+        };
+
+        groups.push(Group {
+            source: i - di..i,
+            target: j - dj..j,
+            confidence,
+        });
+
+        i -= di;
+        j -= dj;
+    }
+
+    groups.reverse();
+
+    groups
+}
+
+fn char_count(s: &str) -> f64 {
+    s.chars().count() as f64
+}
+
+// Probability of a length difference at least as large as this one
+fn match_probability(source_len: f64, target_len: f64, ratio: f64) -> f64 {
+    if source_len == 0.0 && target_len == 0.0 {
+        return 1.0;
+    }
+
+    let mean = (source_len + target_len / ratio) / 2.0;
+    let delta = (target_len - source_len * ratio) / (mean.max(1.0) * VARIANCE).sqrt();
+
+    // Keep the cost finite for wildly different lengths
+    (2.0 * (1.0 - normal_cdf(delta.abs()))).max(f64::MIN_POSITIVE)
+}
+
+// Abramowitz & Stegun 26.2.17
+fn normal_cdf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.2316419 * x);
+    let density = (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt();
+    let tail = density
+        * t
+        * (0.319381530
+            + t * (-0.356563782 + t * (1.781477937 + t * (-1.821255978 + t * 1.330274429))));
+
+    1.0 - tail
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(groups: &[Group]) -> Vec<(Range<usize>, Range<usize>)> {
+        groups
+            .iter()
+            .map(|group| (group.source.clone(), group.target.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn aligns_one_to_one() {
+        let source = [
+            "Short one.",
+            "This sentence is quite a bit longer.",
+            "Mid length.",
+        ];
+        let target = [
+            "Kurzer.",
+            "Dieser Satz ist deutlich laenger als.",
+            "Mittellang.",
+        ];
+
+        let groups = align(&source, &target);
+
+        assert_eq!(
+            ranges(&groups),
+            vec![(0..1, 0..1), (1..2, 1..2), (2..3, 2..3)]
+        );
+        assert!(groups.iter().all(|group| group.confidence > 0.5));
+    }
+
+    #[test]
+    fn merges_split_sentence() {
+        let source = [
+            "The first sentence is rather long and gets split in two.",
+            "Another one.",
+        ];
+        let target = [
+            "The first sentence is rather long",
+            "and it got split in two.",
+            "Another one.",
+        ];
+
+        let groups = align(&source, &target);
+
+        assert_eq!(ranges(&groups), vec![(0..1, 0..2), (1..2, 2..3)]);
+    }
+
+    #[test]
+    fn leaves_untranslated_segments_without_counterpart() {
+        let source = ["Hello.", "World."];
+        let target: [&str; 0] = [];
+
+        let groups = align(&source, &target);
+
+        assert_eq!(ranges(&groups), vec![(0..1, 0..0), (1..2, 0..0)]);
+        assert!(groups.iter().all(|group| group.confidence == 0.0));
+    }
+
+    #[test]
+    fn covers_both_texts_in_order() {
+        let source = ["One.", "Two two.", "Three three three.", "Four."];
+        let target = [
+            "Uno.",
+            "Dos dos.",
+            "Tres tres tres.",
+            "Cuatro.",
+            "Extra bit.",
+        ];
+
+        let groups = align(&source, &target);
+
+        let mut source_end = 0;
+        let mut target_end = 0;
+        for group in &groups {
+            assert_eq!(group.source.start, source_end);
+            assert_eq!(group.target.start, target_end);
+            source_end = group.source.end;
+            target_end = group.target.end;
+        }
+
+        assert_eq!((source_end, target_end), (source.len(), target.len()));
+    }
+
+    #[test]
+    fn handles_empty_input() {
+        let empty: [&str; 0] = [];
+
+        assert!(align(&empty, &empty).is_empty());
+    }
+
+    #[test]
+    fn scores_proportional_lengths_higher() {
+        assert!(match_probability(100.0, 100.0, 1.0) > match_probability(100.0, 150.0, 1.0));
+        assert_eq!(match_probability(0.0, 0.0, 1.0), 1.0);
+        assert!(match_probability(1.0, 10_000.0, 1.0) > 0.0);
+    }
+}
@@ -0,0 +1,426 @@
+use serde::Serialize;
+use std::iter::repeat;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "tag")]
+pub enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Chunk {
+    pub op: Op,
+    pub text: String,
+}
+
+// Word level diff, suitable for inline highlighting
+pub fn words(old: &str, new: &str) -> Vec<Chunk> {
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+
+    let mut chunks: Vec<Chunk> = Vec::new();
+
+    let (mut i, mut j) = (0, 0);
+    for op in ops(&old_tokens, &new_tokens) {
+        let token = match op {
+            Op::Equal => {
+                i += 1;
+                j += 1;
+
+                old_tokens[i - 1]
+            }
+            Op::Delete => {
+                i += 1;
+
+                old_tokens[i - 1]
+            }
+            Op::Insert => {
+                j += 1;
+
+                new_tokens[j - 1]
+            }
+        };
+
+        match chunks.last_mut() {
+            Some(chunk) if chunk.op == op => chunk.text.push_str(token),
+            _ => chunks.push(Chunk {
+                op,
+                text: token.to_owned(),
+            }),
+        }
+    }
+
+    chunks
+}
+
+// Line level diff in the `diff -u` hunk format
+pub fn unified(old: &str, new: &str, context: usize) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    // (op, text, old line index, new line index)
+    let mut lines = Vec::new();
+
+    let (mut i, mut j) = (0, 0);
+    for op in ops(&old_lines, &new_lines) {
+        match op {
+            Op::Equal => {
+                lines.push((op, old_lines[i], i, j));
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => {
+                lines.push((op, old_lines[i], i, j));
+                i += 1;
+            }
+            Op::Insert => {
+                lines.push((op, new_lines[j], i, j));
+                j += 1;
+            }
+        }
+    }
+
+    let mut out = String::new();
+
+    let mut k = 0;
+    while k < lines.len() {
+        let first_change = match lines[k..].iter().position(|line| line.0 != Op::Equal) {
+            Some(position) => k + position,
+            None => break,
+        };
+
+        // Changes separated by less than two contexts worth
+        // of unchanged lines are merged into a single hunk
+        let mut last_change = first_change;
+        for (m, line) in lines.iter().enumerate().skip(first_change) {
+            if line.0 != Op::Equal {
+                last_change = m;
+            } else if m - last_change > 2 * context {
+                break;
+            }
+        }
+
+        let start = first_change.saturating_sub(context).max(k);
+        let end = (last_change + 1 + context).min(lines.len());
+        let hunk = &lines[start..end];
+
+        let old_len = hunk.iter().filter(|line| line.0 != Op::Insert).count();
+        let new_len = hunk.iter().filter(|line| line.0 != Op::Delete).count();
+
+        // Empty ranges point at the line right before them
+        let old_start = if old_len == 0 {
+            hunk[0].2
+        } else {
+            hunk[0].2 + 1
+        };
+        let new_start = if new_len == 0 {
+            hunk[0].3
+        } else {
+            hunk[0].3 + 1
+        };
+
+        out.push_str(&format!(
+            "@@ -{old_start},{old_len} +{new_start},{new_len} @@\n"
+        ));
+
+        for (op, text, _, _) in hunk {
+            let prefix = match op {
+                Op::Equal => ' ',
+                Op::Delete => '-',
+                Op::Insert => '+',
+            };
+
+            out.push(prefix);
+            out.push_str(text);
+            out.push('\n');
+        }
+
+        k = end;
+    }
+
+    out
+}
+
+// Above this many (old token, new token) pairs left after trimming
+// the common prefix and suffix, the changed middle is shown as
+// replaced as a whole instead of being diffed any further
+pub const MAX_DIFF_CELLS: usize = 16_000_000;
+
+// Longest common subsequence in linear space (Hirschberg)
+fn ops<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Op> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old = &old[prefix..old.len() - suffix];
+    let new = &new[prefix..new.len() - suffix];
+
+    let mut ops: Vec<Op> = repeat(Op::Equal).take(prefix).collect();
+
+    if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+        ops.extend(repeat(Op::Delete).take(old.len()));
+        ops.extend(repeat(Op::Insert).take(new.len()));
+    } else {
+        hirschberg(old, new, &mut ops);
+    }
+
+    ops.extend(repeat(Op::Equal).take(suffix));
+
+    ops
+}
+
+fn hirschberg<T: PartialEq>(old: &[T], new: &[T], ops: &mut Vec<Op>) {
+    if old.is_empty() {
+        ops.extend(repeat(Op::Insert).take(new.len()));
+        return;
+    }
+
+    if new.is_empty() {
+        ops.extend(repeat(Op::Delete).take(old.len()));
+        return;
+    }
+
+    if old.len() == 1 {
+        match new.iter().position(|token| *token == old[0]) {
+            Some(position) => {
+                ops.extend(repeat(Op::Insert).take(position));
+                ops.push(Op::Equal);
+                ops.extend(repeat(Op::Insert).take(new.len() - position - 1));
+            }
+            None => {
+                ops.push(Op::Delete);
+                ops.extend(repeat(Op::Insert).take(new.len()));
+            }
+        }
+        return;
+    }
+
+    let middle = old.len() / 2;
+
+    let forward = lcs_lengths(&old[..middle], new);
+    let backward = lcs_lengths_reversed(&old[middle..], new);
+
+    // Where `new` is split so that both halves together
+    // still have the longest common subsequence
+    let mut split = 0;
+    for j in 1..=new.len() {
+        if forward[j] + backward[j] > forward[split] + backward[split] {
+            split = j;
+        }
+    }
+
+    hirschberg(&old[..middle], &new[..split], ops);
+    hirschberg(&old[middle..], &new[split..], ops);
+}
+
+// `lengths[j]` is the LCS length of `old` and `new[..j]`
+fn lcs_lengths<T: PartialEq>(old: &[T], new: &[T]) -> Vec<u32> {
+    let mut lengths = vec![0u32; new.len() + 1];
+
+    for a in old {
+        let mut diagonal = 0;
+        for j in 1..=new.len() {
+            let above = lengths[j];
+            lengths[j] = if *a == new[j - 1] {
+                diagonal + 1
+            } else {
+                above.max(lengths[j - 1])
+            };
+            diagonal = above;
+        }
+    }
+
+    lengths
+}
+
+// `lengths[j]` is the LCS length of `old` and `new[j..]`
+fn lcs_lengths_reversed<T: PartialEq>(old: &[T], new: &[T]) -> Vec<u32> {
+    let mut lengths = vec![0u32; new.len() + 1];
+
+    for a in old.iter().rev() {
+        let mut diagonal = 0;
+        for j in (0..new.len()).rev() {
+            let above = lengths[j];
+            lengths[j] = if *a == new[j] {
+                diagonal + 1
+            } else {
+                above.max(lengths[j + 1])
+            };
+            diagonal = above;
+        }
+    }
+
+    lengths
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Word,
+    Space,
+    Single,
+}
+
+// Splits text into words and whitespace runs.
+// Scripts written without spaces (kanji, kana, ...) and
+// punctuation are split into individual characters instead
+fn tokenize(s: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+
+    let mut start = 0;
+    let mut previous_kind = None;
+
+    for (i, c) in s.char_indices() {
+        let kind = if c.is_whitespace() {
+            TokenKind::Space
+        } else if c.is_alphanumeric() && !is_unspaced(c) {
+            TokenKind::Word
+        } else {
+            TokenKind::Single
+        };
+
+        if let Some(previous_kind) = previous_kind {
+            if previous_kind != kind || kind == TokenKind::Single {
+                tokens.push(&s[start..i]);
+                start = i;
+            }
+        }
+
+        previous_kind = Some(kind);
+    }
+
+    if start < s.len() {
+        tokens.push(&s[start..]);
+    }
+
+    tokens
+}
+
+fn is_unspaced(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF | 0x0E00..=0x0E7F
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(chunks: &[Chunk]) -> Vec<(Op, &str)> {
+        chunks
+            .iter()
+            .map(|chunk| (chunk.op, chunk.text.as_str()))
+            .collect()
+    }
+
+    fn apply(old: &[char], new: &[char]) -> (String, String) {
+        let (mut i, mut j) = (0, 0);
+        let (mut from, mut to) = (String::new(), String::new());
+        for op in ops(old, new) {
+            match op {
+                Op::Equal => {
+                    assert_eq!(old[i], new[j]);
+                    from.push(old[i]);
+                    to.push(new[j]);
+                    i += 1;
+                    j += 1;
+                }
+                Op::Delete => {
+                    from.push(old[i]);
+                    i += 1;
+                }
+                Op::Insert => {
+                    to.push(new[j]);
+                    j += 1;
+                }
+            }
+        }
+        (from, to)
+    }
+
+    #[test]
+    fn words_marks_changed_words() {
+        let chunks = words("the quick fox", "the slow fox");
+
+        assert_eq!(
+            render(&chunks),
+            vec![
+                (Op::Equal, "the "),
+                (Op::Delete, "quick"),
+                (Op::Insert, "slow"),
+                (Op::Equal, " fox"),
+            ]
+        );
+    }
+
+    #[test]
+    fn words_splits_unspaced_scripts_per_char() {
+        let chunks = words("日本語", "日本人");
+
+        assert_eq!(
+            render(&chunks),
+            vec![(Op::Equal, "日本"), (Op::Delete, "語"), (Op::Insert, "人")]
+        );
+    }
+
+    #[test]
+    fn ops_finds_the_longest_common_subsequence() {
+        let old: Vec<char> = "ABCBDAB".chars().collect();
+        let new: Vec<char> = "BDCABA".chars().collect();
+
+        let ops = ops(&old, &new);
+        let equal_count = ops.iter().filter(|op| **op == Op::Equal).count();
+
+        assert_eq!(equal_count, 4);
+        assert_eq!(
+            apply(&old, &new),
+            ("ABCBDAB".to_owned(), "BDCABA".to_owned())
+        );
+    }
+
+    #[test]
+    fn ops_handles_empty_sides() {
+        let text: Vec<char> = "abc".chars().collect();
+
+        assert_eq!(ops(&[], &text), vec![Op::Insert; 3]);
+        assert_eq!(ops(&text, &[]), vec![Op::Delete; 3]);
+        assert_eq!(ops::<char>(&[], &[]), Vec::new());
+    }
+
+    #[test]
+    fn ops_replaces_huge_changes_as_a_whole() {
+        let old = vec![0u8; 5_000];
+        let mut new = vec![1u8; 5_000];
+        new[2_500] = 0;
+
+        let ops = ops(&old, &new);
+
+        assert!(ops[..5_000].iter().all(|op| *op == Op::Delete));
+        assert!(ops[5_000..].iter().all(|op| *op == Op::Insert));
+    }
+
+    #[test]
+    fn unified_keeps_context_around_changes() {
+        let old = "a\nb\nc\nd\ne\n";
+        let new = "a\nb\nC\nd\ne\n";
+
+        assert_eq!(unified(old, new, 1), "@@ -2,3 +2,3 @@\n b\n-c\n+C\n d\n");
+    }
+
+    #[test]
+    fn unified_points_empty_ranges_at_the_previous_line() {
+        assert_eq!(unified("a\n", "a\nb\n", 0), "@@ -1,0 +2,1 @@\n+b\n");
+        assert_eq!(unified("", "", 3), "");
+    }
+}
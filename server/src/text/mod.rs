@@ -0,0 +1,6 @@
+pub mod align;
+pub mod diff;
+pub mod paragraph;
+pub mod segment;
+pub mod similarity;
+pub mod word;
@@ -0,0 +1,22 @@
+// Paragraphs are separated by a blank line
+const SEPARATOR: &'static str = "\n\n";
+
+pub fn split(content: &str) -> Vec<&str> {
+    content.split(SEPARATOR).collect()
+}
+
+pub fn get(content: &str, index: usize) -> Option<&str> {
+    content.split(SEPARATOR).nth(index)
+}
+
+pub fn replace(content: &str, index: usize, paragraph: &str) -> Option<String> {
+    let mut paragraphs = split(content);
+
+    *paragraphs.get_mut(index)? = paragraph;
+
+    Some(paragraphs.join(SEPARATOR))
+}
+
+pub fn join(paragraphs: &[String]) -> String {
+    paragraphs.join(SEPARATOR)
+}
@@ -0,0 +1,70 @@
+use super::paragraph;
+
+const TERMINATORS: [char; 7] = ['.', '!', '?', '…', '。', '！', '？'];
+// Scripts that use these don't put spaces between sentences
+const FULL_WIDTH_TERMINATORS: [char; 3] = ['。', '！', '？'];
+
+// Splits a paragraph into sentences, a sentence ends with a terminator
+// that is followed by whitespace or the end of text
+pub fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if !TERMINATORS.contains(&c) {
+            continue;
+        }
+
+        let is_end = match chars.peek() {
+            Some((_, next)) => next.is_whitespace() || FULL_WIDTH_TERMINATORS.contains(&c),
+            None => true,
+        };
+
+        if is_end {
+            let end = i + c.len_utf8();
+            push_trimmed(&mut sentences, &text[start..end]);
+            start = end;
+        }
+    }
+
+    push_trimmed(&mut sentences, &text[start..]);
+
+    sentences
+}
+
+// Pairs up the segments of a text with the segments of its translation.
+// Paragraphs are matched in order, sentences within a paragraph are only
+// matched when both sides have the same amount of them, otherwise
+// the whole paragraph becomes a single unit
+pub fn pairs<'a>(source: &'a str, target: &'a str) -> Vec<(&'a str, &'a str)> {
+    let source_paragraphs = paragraph::split(source);
+    let target_paragraphs = paragraph::split(target);
+
+    if source_paragraphs.len() != target_paragraphs.len() {
+        return Vec::new();
+    }
+
+    let mut pairs = Vec::new();
+
+    for (source, target) in source_paragraphs.into_iter().zip(target_paragraphs) {
+        let source_sentences = sentences(source);
+        let target_sentences = sentences(target);
+
+        if source_sentences.len() == target_sentences.len() {
+            pairs.extend(source_sentences.into_iter().zip(target_sentences));
+        } else if !source.trim().is_empty() && !target.trim().is_empty() {
+            pairs.push((source.trim(), target.trim()));
+        }
+    }
+
+    pairs
+}
+
+fn push_trimmed<'a>(sentences: &mut Vec<&'a str>, sentence: &'a str) {
+    let sentence = sentence.trim();
+
+    if !sentence.is_empty() {
+        sentences.push(sentence);
+    }
+}
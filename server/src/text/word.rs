@@ -0,0 +1,93 @@
+// Case-insensitive search for `word` as a whole word, so `art` isn't found in `start`.
+// Scripts that don't separate words with spaces (Chinese, Japanese, Thai, ...)
+// have no boundaries to check, there any occurrence counts
+pub fn contains(text: &str, word: &str) -> bool {
+    let text = text.to_lowercase();
+    let word = word.to_lowercase();
+
+    let (first, last) = match (word.chars().next(), word.chars().next_back()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return false,
+    };
+
+    text.char_indices()
+        .filter(|(start, _)| text[*start..].starts_with(&word))
+        .any(|(start, _)| {
+            let before = text[..start].chars().next_back();
+            let after = text[start + word.len()..].chars().next();
+
+            is_boundary(before, first) && is_boundary(after, last)
+        })
+}
+
+fn is_boundary(outside: Option<char>, inside: char) -> bool {
+    match outside {
+        Some(outside) => {
+            !outside.is_alphanumeric()
+                || !inside.is_alphanumeric()
+                || is_unsegmented(outside)
+                || is_unsegmented(inside)
+        }
+        None => true,
+    }
+}
+
+fn is_unsegmented(c: char) -> bool {
+    matches!(c,
+        '\u{0E00}'..='\u{0EFF}' // Thai, Lao
+        | '\u{1000}'..='\u{109F}' // Myanmar
+        | '\u{1780}'..='\u{17FF}' // Khmer
+        | '\u{3040}'..='\u{30FF}' // Hiragana, Katakana
+        | '\u{31F0}'..='\u{31FF}' // Katakana extensions
+        | '\u{3400}'..='\u{4DBF}' // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK unified ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul, particles attach to words
+        | '\u{F900}'..='\u{FAFF}' // CJK compatibility ideographs
+        | '\u{20000}'..='\u{2FA1F}' // CJK extensions B and up
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contains;
+
+    #[test]
+    fn matches_whole_words() {
+        assert!(contains("Art is long", "art"));
+        assert!(contains("the art", "art"));
+        assert!(contains("art", "art"));
+        assert!(!contains("start", "art"));
+        assert!(!contains("artist", "art"));
+    }
+
+    #[test]
+    fn treats_punctuation_as_boundary() {
+        assert!(contains("(art), she said", "art"));
+        assert!(contains("state-of-the-art.", "art"));
+        assert!(contains("use C++ here", "c++"));
+    }
+
+    #[test]
+    fn ignores_case() {
+        assert!(contains("Rust", "rust"));
+        assert!(contains("ПРИВІТ світ", "привіт"));
+    }
+
+    #[test]
+    fn finds_later_occurrence_after_partial_one() {
+        assert!(contains("starting art", "art"));
+        assert!(contains("ananas anan", "anan"));
+    }
+
+    #[test]
+    fn matches_inside_unsegmented_scripts() {
+        assert!(contains("東京都に住んでいる", "東京"));
+        assert!(contains("ภาษาไทยง่าย", "ไทย"));
+        assert!(contains("서울에 살아요", "서울"));
+    }
+
+    #[test]
+    fn never_matches_empty_word() {
+        assert!(!contains("anything", ""));
+    }
+}
@@ -11,6 +11,7 @@ type Status
     = Pending
     | Approved
     | Denied
+    | ChangesRequested
 
 
 type alias Meta =
@@ -93,6 +94,9 @@ encodeStatus status =
 
                 Denied ->
                     "Denied"
+
+                ChangesRequested ->
+                    "ChangesRequested"
     in
     E.object
         [ ( "tag", E.string tag )
@@ -111,6 +115,9 @@ statusInnerDecoder tag =
         "Denied" ->
             D.succeed Denied
 
+        "ChangesRequested" ->
+            D.succeed ChangesRequested
+
         _ ->
             D.fail <| "Encountered unknown tag `" ++ tag ++ "` while decoding Post.Status"
 
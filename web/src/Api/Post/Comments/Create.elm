@@ -16,6 +16,7 @@ type alias Request =
 
 type Response
     = Unauthorized
+    | InvalidReplyTarget
     | Success
 
 
@@ -51,6 +52,9 @@ responseInnerDecoder tag =
         "Unauthorized" ->
             D.succeed Unauthorized
 
+        "InvalidReplyTarget" ->
+            D.succeed InvalidReplyTarget
+
         "Success" ->
             D.succeed Success
 
@@ -234,6 +234,9 @@ update id shared msg model =
                 Ok PostCommentsCreate.Unauthorized ->
                     ( model, reauth )
 
+                Ok PostCommentsCreate.InvalidReplyTarget ->
+                    ( { model | commentCreateStatus = Error CommentCreateError.InvalidReplyTarget }, Effect.none )
+
                 Ok PostCommentsCreate.Success ->
                     ( { model | commentCreateStatus = Success (), commentContent = "" }, Effect.none )
 
@@ -245,6 +248,9 @@ update id shared msg model =
                 Ok PostCommentsCreate.Unauthorized ->
                     ( model, reauth )
 
+                Ok PostCommentsCreate.InvalidReplyTarget ->
+                    ( { model | replyCreateStatus = Error CommentCreateError.InvalidReplyTarget }, Effect.none )
+
                 Ok PostCommentsCreate.Success ->
                     ( { model | replyCreateStatus = Success (), replyTo = Nothing, replyContent = "" }, Effect.none )
 
@@ -507,6 +507,14 @@ translatePostStatus language status =
                 Ukrainian ->
                     "Відхилено"
 
+        Post.ChangesRequested ->
+            case language of
+                English ->
+                    "Changes requested"
+
+                Ukrainian ->
+                    "Потрібні зміни"
+
 
 viewPostStatus : Shared.Model -> Bool -> Int -> Post.Status -> Html Msg
 viewPostStatus shared isMine i status =
@@ -534,6 +542,9 @@ viewPostStatus shared isMine i status =
 
                 Post.Denied ->
                     errorMessageSpan shared s
+
+                Post.ChangesRequested ->
+                    messageSpan shared s
             ]
 
     else
@@ -544,16 +555,10 @@ viewActionButtons : Shared.Model -> Int -> Post.Status -> Html Msg
 viewActionButtons shared i status =
     case status of
         Post.Pending ->
-            div
-                [ css
-                    [ displayFlex
-                    , property "gap" "0.5rem"
-                    , flexWrap wrap
-                    ]
-                ]
-                [ viewApproveButton shared i
-                , viewDenyButton shared i
-                ]
+            viewPendingActionButtons shared i
+
+        Post.ChangesRequested ->
+            viewPendingActionButtons shared i
 
         Post.Approved ->
             viewDenyButton shared i
@@ -562,6 +567,20 @@ viewActionButtons shared i status =
             viewApproveButton shared i
 
 
+viewPendingActionButtons : Shared.Model -> Int -> Html Msg
+viewPendingActionButtons shared i =
+    div
+        [ css
+            [ displayFlex
+            , property "gap" "0.5rem"
+            , flexWrap wrap
+            ]
+        ]
+        [ viewApproveButton shared i
+        , viewDenyButton shared i
+        ]
+
+
 viewApproveButton : Shared.Model -> Int -> Html Msg
 viewApproveButton shared i =
     button
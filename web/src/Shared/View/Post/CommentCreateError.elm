@@ -5,6 +5,7 @@ import Language exposing (Language(..))
 
 type CommentCreateError
     = ContentIsRequired
+    | InvalidReplyTarget
     | ServerError
 
 
@@ -19,6 +20,14 @@ translate language error =
                 Ukrainian ->
                     "Введіть зміст"
 
+        InvalidReplyTarget ->
+            case language of
+                English ->
+                    "The comment you are replying to is no longer available"
+
+                Ukrainian ->
+                    "Коментар, на який ви відповідаєте, більше недоступний"
+
         ServerError ->
             case language of
                 English ->